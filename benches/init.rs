@@ -0,0 +1,52 @@
+use criterion::{black_box, criterion_group, criterion_main, BatchSize, Criterion};
+use phonenumber::metadata::{loader, Database};
+use phonenumber::Mode;
+use std::fs::File;
+use std::io::BufReader;
+use std::time::Duration;
+
+fn first_query(database: &Database) -> String {
+    phonenumber::parse_with(database, None, black_box("+441212345678"))
+        .unwrap()
+        .format_with(database)
+        .mode(Mode::International)
+        .to_string()
+}
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let metadata = loader::load(BufReader::new(
+        File::open("assets/PhoneNumberMetadata.xml").unwrap(),
+    ))
+    .unwrap();
+
+    let mut group = c.benchmark_group("first_query");
+    group
+        .sample_size(10)
+        .warm_up_time(Duration::from_secs(1))
+        .measurement_time(Duration::from_secs(2));
+
+    group.bench_function("cold", |b| {
+        b.iter_batched_ref(
+            || Database::from(metadata.clone()).unwrap(),
+            |database| first_query(database),
+            BatchSize::PerIteration,
+        )
+    });
+
+    group.bench_function("warm", |b| {
+        b.iter_batched_ref(
+            || {
+                let database = Database::from(metadata.clone()).unwrap();
+                database.warm_up();
+                database
+            },
+            |database| first_query(database),
+            BatchSize::PerIteration,
+        )
+    });
+
+    group.finish();
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
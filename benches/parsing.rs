@@ -23,6 +23,84 @@ pub fn criterion_benchmark(c: &mut Criterion) {
             })
         });
     }
+
+    let e164_cases = [
+        "+80012340000",
+        "+61406823897",
+        "+441212345678",
+        "+16137827274",
+    ];
+
+    for case in e164_cases {
+        c.bench_with_input(BenchmarkId::new("parse_e164", case), &case, |b, case| {
+            b.iter(|| {
+                let pn = black_box(case);
+                phonenumber::parse_e164(pn)
+            })
+        });
+    }
+
+    let rfc3966_cases = [
+        "tel:+80012340000",
+        "tel:+61-406-823-897",
+        "tel:+44-121-234-5678",
+        "tel:+1-520-878-2491",
+        "tel:+1-520-878-2491;ext=123",
+        "tel:+1-520-878-2491;isub=1234;ext=123",
+        "tel:253-0000;phone-context=+64-3",
+        "tel:253-0000;phone-context=www.google.com",
+    ];
+
+    for case in rfc3966_cases {
+        c.bench_with_input(BenchmarkId::new("parse_rfc3966", case), &case, |b, case| {
+            b.iter(|| {
+                let pn = black_box(case);
+                phonenumber::parse(None, pn)
+            })
+        });
+    }
+
+    // Numbers sharing the country code 1, whose region has to be looked up.
+    let nanpa_cases = [
+        "+1 650 253 0000",
+        "+1 520 878 2491",
+        "+1 613 782 7274",
+        "+1 604 555 5555",
+        "+1 345 949 2311",
+        "+1 268 460 1234",
+        "+1 809 234 5678",
+        "+1 787 234 5678",
+        "+1 876 523 4567",
+        "+1 340 642 1234",
+    ];
+
+    c.bench_function("parse_nanpa", |b| {
+        b.iter(|| {
+            for case in nanpa_cases {
+                let _ = phonenumber::parse(None, black_box(case)).map(|pn| pn.is_valid());
+            }
+        })
+    });
+
+    let threads = 8;
+
+    c.bench_function("parse_threaded", |b| {
+        b.iter(|| {
+            let handles = (0..threads)
+                .map(|_| {
+                    std::thread::spawn(move || {
+                        for case in cases {
+                            let _ = phonenumber::parse(None, black_box(case));
+                        }
+                    })
+                })
+                .collect::<Vec<_>>();
+
+            for handle in handles {
+                handle.join().unwrap();
+            }
+        })
+    });
 }
 
 criterion_group!(benches, criterion_benchmark);
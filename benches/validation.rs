@@ -0,0 +1,30 @@
+use criterion::{black_box, criterion_group, criterion_main, Criterion};
+use phonenumber::metadata::DATABASE;
+
+pub fn criterion_benchmark(c: &mut Criterion) {
+    let numbers = DATABASE
+        .all_example_numbers()
+        .filter_map(|(id, _, example)| phonenumber::parse(Some(id), example).ok())
+        .collect::<Vec<_>>();
+
+    c.bench_function("is_valid", |b| {
+        b.iter(|| {
+            numbers
+                .iter()
+                .filter(|number| phonenumber::is_valid(black_box(number)))
+                .count()
+        })
+    });
+
+    c.bench_function("is_possible", |b| {
+        b.iter(|| {
+            numbers
+                .iter()
+                .filter(|number| phonenumber::is_possible(black_box(number)))
+                .count()
+        })
+    });
+}
+
+criterion_group!(benches, criterion_benchmark);
+criterion_main!(benches);
@@ -19,6 +19,26 @@ fn main() {
     .expect("failed to load metadata");
     println!("cargo:rerun-if-changed={pnm_path}");
 
+    // Optionally only include the comma separated list of regions, to shrink
+    // the embedded database.
+    println!("cargo:rerun-if-env-changed=PHONENUMBER_REGIONS");
+    let metadata = match env::var("PHONENUMBER_REGIONS") {
+        Ok(regions) => {
+            let regions = regions
+                .split(',')
+                .map(|id| id.trim().to_uppercase())
+                .filter(|id| !id.is_empty())
+                .collect::<Vec<_>>();
+
+            metadata
+                .into_iter()
+                .filter(|meta| meta.id.as_ref().map_or(false, |id| regions.contains(id)))
+                .collect()
+        }
+
+        Err(_) => metadata,
+    };
+
     let mut out = BufWriter::new(
         File::create(Path::new(&env::var("OUT_DIR").unwrap()).join("database.bin"))
             .expect("could not create database file"),
@@ -28,4 +48,62 @@ fn main() {
         .with_varint_encoding()
         .serialize_into(&mut out, &metadata)
         .expect("failed to serialize database");
+
+    #[cfg(feature = "geocoder")]
+    geocoding();
+}
+
+/// Collect the prefix descriptions of every locale in `assets/geocoding` and
+/// serialize them, sorted by locale and prefix, into `geocoding.bin`.
+#[cfg(feature = "geocoder")]
+fn geocoding() {
+    use std::fs;
+    use std::io::BufRead;
+
+    let geo_path = "assets/geocoding";
+    println!("cargo:rerun-if-changed={geo_path}");
+
+    let mut locales = Vec::new();
+
+    for locale in fs::read_dir(geo_path).expect("could not open geocoding directory") {
+        let locale = locale.expect("could not read geocoding directory");
+        let mut prefixes = Vec::new();
+
+        for file in fs::read_dir(locale.path()).expect("could not open geocoding locale") {
+            let file = file.expect("could not read geocoding locale");
+            let reader =
+                BufReader::new(File::open(file.path()).expect("could not open geocoding file"));
+
+            for line in reader.lines() {
+                let line = line.expect("could not read geocoding file");
+                let line = line.trim();
+
+                if line.is_empty() || line.starts_with('#') {
+                    continue;
+                }
+
+                let (prefix, description) = line.split_once('|').expect("malformed geocoding line");
+
+                prefixes.push((
+                    prefix.parse::<u64>().expect("malformed geocoding prefix"),
+                    description.to_owned(),
+                ));
+            }
+        }
+
+        prefixes.sort();
+        locales.push((locale.file_name().into_string().unwrap(), prefixes));
+    }
+
+    locales.sort();
+
+    let mut out = BufWriter::new(
+        File::create(Path::new(&env::var("OUT_DIR").unwrap()).join("geocoding.bin"))
+            .expect("could not create geocoding file"),
+    );
+
+    bincode::options()
+        .with_varint_encoding()
+        .serialize_into(&mut out, &locales)
+        .expect("failed to serialize geocoding data");
 }
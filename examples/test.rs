@@ -5,6 +5,11 @@ use phonenumber::Mode;
 fn main() {
     let mut args = env::args().skip(1).collect::<Vec<_>>();
 
+    let mode = args.iter().position(|a| a == "--format").map(|index| {
+        args.remove(index);
+        args.remove(index).parse::<Mode>().unwrap()
+    });
+
     if args.is_empty() {
         panic!("not enough arguments");
     }
@@ -15,7 +20,9 @@ fn main() {
     let number = phonenumber::parse(country, number).unwrap();
     let valid = phonenumber::is_valid(&number);
 
-    if valid {
+    if let Some(mode) = mode {
+        println!("{}", number.format().mode(mode));
+    } else if valid {
         println!("\x1b[32m{:#?}\x1b[0m", number);
         println!();
         println!(
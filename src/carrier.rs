@@ -17,7 +17,11 @@ use std::fmt;
 use std::ops::Deref;
 
 /// A phone number carrier.
-#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Hash, Debug)]
+///
+/// This is the carrier selection code as found in the parsed number (for
+/// example the `15` in the Brazilian `0 15 21 5555-5555`), it is not split
+/// into a mobile country code and mobile network code.
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Hash, Debug)]
 pub struct Carrier(pub(crate) String);
 
 impl<T: Into<String>> From<T> for Carrier {
@@ -40,6 +44,7 @@ impl AsRef<str> for Carrier {
     }
 }
 
+/// Writes the carrier code verbatim, without any separators.
 impl fmt::Display for Carrier {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.0)
@@ -24,6 +24,9 @@ pub const MIN_LENGTH_FOR_NSN: usize = 2;
 /// The maximum length of the National Significant Number.
 pub const MAX_LENGTH_FOR_NSN: usize = 17;
 
+/// The maximum length of an extension, as defined by the ITU.
+pub const MAX_LENGTH_FOR_EXTENSION: usize = 40;
+
 /// The maximum length of the country calling code.
 pub const MAX_LENGTH_FOR_COUNTRY_CODE: usize = 3;
 
@@ -360,6 +363,24 @@ pub static VALID_PHONE_NUMBER: Lazy<Regex> = Lazy::new(|| {
         .unwrap()
 });
 
+/// Pattern matching a phone number, with an optional extension, at the start
+/// of a string, for use when parsing numbers followed by unrelated text.
+/// Letters are not allowed outside of the extension, so alpha numbers are not
+/// supported here.
+pub static PHONE_NUMBER_PREFIX: Lazy<Regex> = Lazy::new(|| {
+    RegexBuilder::new(&format!(
+        r"^[{plus}]*(?:[{punctuation}*]*{digits}){{{min}}}[{punctuation}*{digits}]*(?:{extension})?",
+        plus = PLUS_CHARS,
+        punctuation = VALID_PUNCTUATION,
+        digits = DIGITS,
+        min = MIN_LENGTH_FOR_NSN,
+        extension = *EXTN_PATTERNS_FOR_PARSING
+    ))
+    .case_insensitive(true)
+    .build()
+    .unwrap()
+});
+
 pub static NON_DIGITS: Lazy<Regex> = Lazy::new(|| Regex::new(r"(\D+)").unwrap());
 
 /// The FIRST_GROUP_PATTERN was originally set to $1 but there are some
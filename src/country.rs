@@ -14,11 +14,14 @@
 
 //! Country related types.
 
+use crate::metadata::{Database, DATABASE};
+use crate::parser;
+use crate::phone_number::PhoneNumber;
 use serde_derive::{Deserialize, Serialize};
 use std::str;
-use strum::{AsRefStr, EnumString};
+use strum::{AsRefStr, EnumIter, EnumString, IntoEnumIterator};
 
-#[derive(Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Hash, Debug)]
+#[derive(Copy, Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Hash, Debug)]
 pub struct Code {
     /// The country code value.
     pub(crate) value: u16,
@@ -29,7 +32,7 @@ pub struct Code {
 
 /// The source from which the country code is derived. This is not set in the
 /// general parsing method, but in the method that parses and keeps raw_input.
-#[derive(Eq, PartialEq, Copy, Clone, Serialize, Deserialize, Hash, Debug)]
+#[derive(Eq, PartialEq, Ord, PartialOrd, Copy, Clone, Serialize, Deserialize, Hash, Debug)]
 #[serde(rename_all = "snake_case")]
 pub enum Source {
     /// The country code is derived based on a phone number with a leading "+",
@@ -70,6 +73,31 @@ impl Code {
     pub fn source(&self) -> Source {
         self.source
     }
+
+    /// Get an example number for every region sharing this country code, for
+    /// example both the US and Canada for `+1`.
+    ///
+    /// The general example of a region is used if there is one, otherwise the
+    /// fixed-line or mobile example. Examples failing to parse are skipped.
+    pub fn example_numbers(&self, database: &Database) -> Vec<PhoneNumber> {
+        database
+            .by_code(&self.value)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|meta| {
+                let descriptors = meta.descriptors();
+
+                descriptors
+                    .general()
+                    .example()
+                    .or_else(|| descriptors.fixed_line().and_then(|d| d.example()))
+                    .or_else(|| descriptors.mobile().and_then(|d| d.example()))
+            })
+            .filter_map(|example| {
+                parser::parse_with(database, None, format!("+{}{}", self.value, example)).ok()
+            })
+            .collect()
+    }
 }
 
 impl From<Code> for u16 {
@@ -79,7 +107,9 @@ impl From<Code> for u16 {
 }
 
 /// CLDR country IDs.
-#[derive(Eq, PartialEq, Copy, Clone, Serialize, Deserialize, Hash, Debug, EnumString, AsRefStr)]
+#[derive(
+    Eq, PartialEq, Copy, Clone, Serialize, Deserialize, Hash, Debug, EnumString, AsRefStr, EnumIter,
+)]
 pub enum Id {
     AC,
     AD,
@@ -329,3 +359,658 @@ pub enum Id {
 }
 
 pub use Id::*;
+
+/// English names of the countries, in the same order as the `Id` variants.
+const NAMES: [(Id, &str); 245] = [
+    (AC, "Ascension Island"),
+    (AD, "Andorra"),
+    (AE, "United Arab Emirates"),
+    (AF, "Afghanistan"),
+    (AG, "Antigua & Barbuda"),
+    (AI, "Anguilla"),
+    (AL, "Albania"),
+    (AM, "Armenia"),
+    (AO, "Angola"),
+    (AR, "Argentina"),
+    (AS, "American Samoa"),
+    (AT, "Austria"),
+    (AU, "Australia"),
+    (AW, "Aruba"),
+    (AX, "Åland Islands"),
+    (AZ, "Azerbaijan"),
+    (BA, "Bosnia & Herzegovina"),
+    (BB, "Barbados"),
+    (BD, "Bangladesh"),
+    (BE, "Belgium"),
+    (BF, "Burkina Faso"),
+    (BG, "Bulgaria"),
+    (BH, "Bahrain"),
+    (BI, "Burundi"),
+    (BJ, "Benin"),
+    (BL, "St. Barthélemy"),
+    (BM, "Bermuda"),
+    (BN, "Brunei"),
+    (BO, "Bolivia"),
+    (BQ, "Caribbean Netherlands"),
+    (BR, "Brazil"),
+    (BS, "Bahamas"),
+    (BT, "Bhutan"),
+    (BW, "Botswana"),
+    (BY, "Belarus"),
+    (BZ, "Belize"),
+    (CA, "Canada"),
+    (CC, "Cocos (Keeling) Islands"),
+    (CD, "Congo - Kinshasa"),
+    (CF, "Central African Republic"),
+    (CG, "Congo - Brazzaville"),
+    (CH, "Switzerland"),
+    (CI, "Côte d’Ivoire"),
+    (CK, "Cook Islands"),
+    (CL, "Chile"),
+    (CM, "Cameroon"),
+    (CN, "China"),
+    (CO, "Colombia"),
+    (CR, "Costa Rica"),
+    (CU, "Cuba"),
+    (CV, "Cape Verde"),
+    (CW, "Curaçao"),
+    (CX, "Christmas Island"),
+    (CY, "Cyprus"),
+    (CZ, "Czechia"),
+    (DE, "Germany"),
+    (DJ, "Djibouti"),
+    (DK, "Denmark"),
+    (DM, "Dominica"),
+    (DO, "Dominican Republic"),
+    (DZ, "Algeria"),
+    (EC, "Ecuador"),
+    (EE, "Estonia"),
+    (EG, "Egypt"),
+    (EH, "Western Sahara"),
+    (ER, "Eritrea"),
+    (ES, "Spain"),
+    (ET, "Ethiopia"),
+    (FI, "Finland"),
+    (FJ, "Fiji"),
+    (FK, "Falkland Islands"),
+    (FM, "Micronesia"),
+    (FO, "Faroe Islands"),
+    (FR, "France"),
+    (GA, "Gabon"),
+    (GB, "United Kingdom"),
+    (GD, "Grenada"),
+    (GE, "Georgia"),
+    (GF, "French Guiana"),
+    (GG, "Guernsey"),
+    (GH, "Ghana"),
+    (GI, "Gibraltar"),
+    (GL, "Greenland"),
+    (GM, "Gambia"),
+    (GN, "Guinea"),
+    (GP, "Guadeloupe"),
+    (GQ, "Equatorial Guinea"),
+    (GR, "Greece"),
+    (GT, "Guatemala"),
+    (GU, "Guam"),
+    (GW, "Guinea-Bissau"),
+    (GY, "Guyana"),
+    (HK, "Hong Kong"),
+    (HN, "Honduras"),
+    (HR, "Croatia"),
+    (HT, "Haiti"),
+    (HU, "Hungary"),
+    (ID, "Indonesia"),
+    (IE, "Ireland"),
+    (IL, "Israel"),
+    (IM, "Isle of Man"),
+    (IN, "India"),
+    (IO, "British Indian Ocean Territory"),
+    (IQ, "Iraq"),
+    (IR, "Iran"),
+    (IS, "Iceland"),
+    (IT, "Italy"),
+    (JE, "Jersey"),
+    (JM, "Jamaica"),
+    (JO, "Jordan"),
+    (JP, "Japan"),
+    (KE, "Kenya"),
+    (KG, "Kyrgyzstan"),
+    (KH, "Cambodia"),
+    (KI, "Kiribati"),
+    (KM, "Comoros"),
+    (KN, "St. Kitts & Nevis"),
+    (KP, "North Korea"),
+    (KR, "South Korea"),
+    (KW, "Kuwait"),
+    (KY, "Cayman Islands"),
+    (KZ, "Kazakhstan"),
+    (LA, "Laos"),
+    (LB, "Lebanon"),
+    (LC, "St. Lucia"),
+    (LI, "Liechtenstein"),
+    (LK, "Sri Lanka"),
+    (LR, "Liberia"),
+    (LS, "Lesotho"),
+    (LT, "Lithuania"),
+    (LU, "Luxembourg"),
+    (LV, "Latvia"),
+    (LY, "Libya"),
+    (MA, "Morocco"),
+    (MC, "Monaco"),
+    (MD, "Moldova"),
+    (ME, "Montenegro"),
+    (MF, "St. Martin"),
+    (MG, "Madagascar"),
+    (MH, "Marshall Islands"),
+    (MK, "North Macedonia"),
+    (ML, "Mali"),
+    (MM, "Myanmar (Burma)"),
+    (MN, "Mongolia"),
+    (MO, "Macao"),
+    (MP, "Northern Mariana Islands"),
+    (MQ, "Martinique"),
+    (MR, "Mauritania"),
+    (MS, "Montserrat"),
+    (MT, "Malta"),
+    (MU, "Mauritius"),
+    (MV, "Maldives"),
+    (MW, "Malawi"),
+    (MX, "Mexico"),
+    (MY, "Malaysia"),
+    (MZ, "Mozambique"),
+    (NA, "Namibia"),
+    (NC, "New Caledonia"),
+    (NE, "Niger"),
+    (NF, "Norfolk Island"),
+    (NG, "Nigeria"),
+    (NI, "Nicaragua"),
+    (NL, "Netherlands"),
+    (NO, "Norway"),
+    (NP, "Nepal"),
+    (NR, "Nauru"),
+    (NU, "Niue"),
+    (NZ, "New Zealand"),
+    (OM, "Oman"),
+    (PA, "Panama"),
+    (PE, "Peru"),
+    (PF, "French Polynesia"),
+    (PG, "Papua New Guinea"),
+    (PH, "Philippines"),
+    (PK, "Pakistan"),
+    (PL, "Poland"),
+    (PM, "St. Pierre & Miquelon"),
+    (PR, "Puerto Rico"),
+    (PS, "Palestinian Territories"),
+    (PT, "Portugal"),
+    (PW, "Palau"),
+    (PY, "Paraguay"),
+    (QA, "Qatar"),
+    (RE, "Réunion"),
+    (RO, "Romania"),
+    (RS, "Serbia"),
+    (RU, "Russia"),
+    (RW, "Rwanda"),
+    (SA, "Saudi Arabia"),
+    (SB, "Solomon Islands"),
+    (SC, "Seychelles"),
+    (SD, "Sudan"),
+    (SE, "Sweden"),
+    (SG, "Singapore"),
+    (SH, "St. Helena"),
+    (SI, "Slovenia"),
+    (SJ, "Svalbard & Jan Mayen"),
+    (SK, "Slovakia"),
+    (SL, "Sierra Leone"),
+    (SM, "San Marino"),
+    (SN, "Senegal"),
+    (SO, "Somalia"),
+    (SR, "Suriname"),
+    (SS, "South Sudan"),
+    (ST, "São Tomé & Príncipe"),
+    (SV, "El Salvador"),
+    (SX, "Sint Maarten"),
+    (SY, "Syria"),
+    (SZ, "Eswatini"),
+    (TA, "Tristan da Cunha"),
+    (TC, "Turks & Caicos Islands"),
+    (TD, "Chad"),
+    (TG, "Togo"),
+    (TH, "Thailand"),
+    (TJ, "Tajikistan"),
+    (TK, "Tokelau"),
+    (TL, "Timor-Leste"),
+    (TM, "Turkmenistan"),
+    (TN, "Tunisia"),
+    (TO, "Tonga"),
+    (TR, "Türkiye"),
+    (TT, "Trinidad & Tobago"),
+    (TV, "Tuvalu"),
+    (TW, "Taiwan"),
+    (TZ, "Tanzania"),
+    (UA, "Ukraine"),
+    (UG, "Uganda"),
+    (US, "United States"),
+    (UY, "Uruguay"),
+    (UZ, "Uzbekistan"),
+    (VA, "Vatican City"),
+    (VC, "St. Vincent & Grenadines"),
+    (VE, "Venezuela"),
+    (VG, "British Virgin Islands"),
+    (VI, "U.S. Virgin Islands"),
+    (VN, "Vietnam"),
+    (VU, "Vanuatu"),
+    (WF, "Wallis & Futuna"),
+    (WS, "Samoa"),
+    (XK, "Kosovo"),
+    (YE, "Yemen"),
+    (YT, "Mayotte"),
+    (ZA, "South Africa"),
+    (ZM, "Zambia"),
+    (ZW, "Zimbabwe"),
+];
+
+/// Primary language of the countries, as ISO 639 codes, in the same order as
+/// the `Id` variants.
+const LANGUAGES: [(Id, &str); 245] = [
+    (AC, "en"),
+    (AD, "ca"),
+    (AE, "ar"),
+    (AF, "fa"),
+    (AG, "en"),
+    (AI, "en"),
+    (AL, "sq"),
+    (AM, "hy"),
+    (AO, "pt"),
+    (AR, "es"),
+    (AS, "sm"),
+    (AT, "de"),
+    (AU, "en"),
+    (AW, "nl"),
+    (AX, "sv"),
+    (AZ, "az"),
+    (BA, "bs"),
+    (BB, "en"),
+    (BD, "bn"),
+    (BE, "nl"),
+    (BF, "fr"),
+    (BG, "bg"),
+    (BH, "ar"),
+    (BI, "rn"),
+    (BJ, "fr"),
+    (BL, "fr"),
+    (BM, "en"),
+    (BN, "ms"),
+    (BO, "es"),
+    (BQ, "nl"),
+    (BR, "pt"),
+    (BS, "en"),
+    (BT, "dz"),
+    (BW, "en"),
+    (BY, "be"),
+    (BZ, "en"),
+    (CA, "en"),
+    (CC, "ms"),
+    (CD, "sw"),
+    (CF, "fr"),
+    (CG, "fr"),
+    (CH, "de"),
+    (CI, "fr"),
+    (CK, "en"),
+    (CL, "es"),
+    (CM, "fr"),
+    (CN, "zh"),
+    (CO, "es"),
+    (CR, "es"),
+    (CU, "es"),
+    (CV, "pt"),
+    (CW, "pap"),
+    (CX, "en"),
+    (CY, "el"),
+    (CZ, "cs"),
+    (DE, "de"),
+    (DJ, "aa"),
+    (DK, "da"),
+    (DM, "en"),
+    (DO, "es"),
+    (DZ, "ar"),
+    (EC, "es"),
+    (EE, "et"),
+    (EG, "ar"),
+    (EH, "ar"),
+    (ER, "ti"),
+    (ES, "es"),
+    (ET, "am"),
+    (FI, "fi"),
+    (FJ, "en"),
+    (FK, "en"),
+    (FM, "en"),
+    (FO, "fo"),
+    (FR, "fr"),
+    (GA, "fr"),
+    (GB, "en"),
+    (GD, "en"),
+    (GE, "ka"),
+    (GF, "fr"),
+    (GG, "en"),
+    (GH, "ak"),
+    (GI, "en"),
+    (GL, "kl"),
+    (GM, "en"),
+    (GN, "fr"),
+    (GP, "fr"),
+    (GQ, "es"),
+    (GR, "el"),
+    (GT, "es"),
+    (GU, "en"),
+    (GW, "pt"),
+    (GY, "en"),
+    (HK, "zh"),
+    (HN, "es"),
+    (HR, "hr"),
+    (HT, "ht"),
+    (HU, "hu"),
+    (ID, "id"),
+    (IE, "en"),
+    (IL, "he"),
+    (IM, "en"),
+    (IN, "hi"),
+    (IO, "en"),
+    (IQ, "ar"),
+    (IR, "fa"),
+    (IS, "is"),
+    (IT, "it"),
+    (JE, "en"),
+    (JM, "en"),
+    (JO, "ar"),
+    (JP, "ja"),
+    (KE, "sw"),
+    (KG, "ky"),
+    (KH, "km"),
+    (KI, "en"),
+    (KM, "ar"),
+    (KN, "en"),
+    (KP, "ko"),
+    (KR, "ko"),
+    (KW, "ar"),
+    (KY, "en"),
+    (KZ, "ru"),
+    (LA, "lo"),
+    (LB, "ar"),
+    (LC, "en"),
+    (LI, "de"),
+    (LK, "si"),
+    (LR, "en"),
+    (LS, "st"),
+    (LT, "lt"),
+    (LU, "lb"),
+    (LV, "lv"),
+    (LY, "ar"),
+    (MA, "ar"),
+    (MC, "fr"),
+    (MD, "ro"),
+    (ME, "sr"),
+    (MF, "fr"),
+    (MG, "mg"),
+    (MH, "en"),
+    (MK, "mk"),
+    (ML, "bm"),
+    (MM, "my"),
+    (MN, "mn"),
+    (MO, "zh"),
+    (MP, "en"),
+    (MQ, "fr"),
+    (MR, "ar"),
+    (MS, "en"),
+    (MT, "mt"),
+    (MU, "mfe"),
+    (MV, "dv"),
+    (MW, "en"),
+    (MX, "es"),
+    (MY, "ms"),
+    (MZ, "pt"),
+    (NA, "af"),
+    (NC, "fr"),
+    (NE, "ha"),
+    (NF, "en"),
+    (NG, "en"),
+    (NI, "es"),
+    (NL, "nl"),
+    (NO, "nb"),
+    (NP, "ne"),
+    (NR, "en"),
+    (NU, "en"),
+    (NZ, "en"),
+    (OM, "ar"),
+    (PA, "es"),
+    (PE, "es"),
+    (PF, "fr"),
+    (PG, "tpi"),
+    (PH, "en"),
+    (PK, "ur"),
+    (PL, "pl"),
+    (PM, "fr"),
+    (PR, "es"),
+    (PS, "ar"),
+    (PT, "pt"),
+    (PW, "en"),
+    (PY, "gn"),
+    (QA, "ar"),
+    (RE, "fr"),
+    (RO, "ro"),
+    (RS, "sr"),
+    (RU, "ru"),
+    (RW, "rw"),
+    (SA, "ar"),
+    (SB, "en"),
+    (SC, "fr"),
+    (SD, "ar"),
+    (SE, "sv"),
+    (SG, "en"),
+    (SH, "en"),
+    (SI, "sl"),
+    (SJ, "nb"),
+    (SK, "sk"),
+    (SL, "kri"),
+    (SM, "it"),
+    (SN, "wo"),
+    (SO, "so"),
+    (SR, "nl"),
+    (SS, "ar"),
+    (ST, "pt"),
+    (SV, "es"),
+    (SX, "en"),
+    (SY, "ar"),
+    (SZ, "en"),
+    (TA, "en"),
+    (TC, "en"),
+    (TD, "fr"),
+    (TG, "fr"),
+    (TH, "th"),
+    (TJ, "tg"),
+    (TK, "tkl"),
+    (TL, "pt"),
+    (TM, "tk"),
+    (TN, "ar"),
+    (TO, "to"),
+    (TR, "tr"),
+    (TT, "en"),
+    (TV, "tvl"),
+    (TW, "zh"),
+    (TZ, "sw"),
+    (UA, "uk"),
+    (UG, "sw"),
+    (US, "en"),
+    (UY, "es"),
+    (UZ, "uz"),
+    (VA, "it"),
+    (VC, "en"),
+    (VE, "es"),
+    (VG, "en"),
+    (VI, "en"),
+    (VN, "vi"),
+    (VU, "bi"),
+    (WF, "wls"),
+    (WS, "sm"),
+    (XK, "sq"),
+    (YE, "ar"),
+    (YT, "fr"),
+    (ZA, "en"),
+    (ZM, "en"),
+    (ZW, "sn"),
+];
+
+impl Id {
+    /// Iterate over all the countries, in alphabetical order of their ID.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::country;
+    ///
+    /// assert_eq!(Some(country::AC), country::Id::all().next());
+    /// assert!(country::Id::all().any(|id| id == country::NZ));
+    /// ```
+    pub fn all() -> impl Iterator<Item = Id> {
+        Id::iter()
+    }
+
+    /// The English name of the country.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::country;
+    ///
+    /// assert_eq!("New Zealand", country::NZ.name());
+    /// ```
+    pub fn name(self) -> &'static str {
+        NAMES[self as usize].1
+    }
+
+    /// The primary language spoken in the country, as an ISO 639 language
+    /// code, useful as a hint when choosing a display locale.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::country;
+    ///
+    /// assert_eq!("de", country::CH.primary_language());
+    /// assert_eq!("ja", country::JP.primary_language());
+    /// ```
+    pub fn primary_language(self) -> &'static str {
+        LANGUAGES[self as usize].1
+    }
+
+    /// Find a country by its English name, ignoring ASCII case.
+    pub fn from_name(name: &str) -> Option<Id> {
+        NAMES
+            .iter()
+            .find(|(_, n)| n.eq_ignore_ascii_case(name))
+            .map(|&(id, _)| id)
+    }
+}
+
+/// Get the country code of the given country.
+///
+/// # Example
+///
+/// ```
+/// use phonenumber::country;
+///
+/// assert_eq!(Some(44), phonenumber::country_code_for(country::GB));
+/// ```
+pub fn country_code_for(id: Id) -> Option<u16> {
+    DATABASE.country_code_for(id)
+}
+
+/// Get all countries sharing the given country code, with the main country
+/// for the code first.
+///
+/// # Example
+///
+/// ```
+/// use phonenumber::country;
+///
+/// let regions = phonenumber::regions_for(1);
+/// assert_eq!(country::US, regions[0]);
+/// assert!(regions.contains(&country::CA));
+/// ```
+pub fn regions_for(code: u16) -> Vec<Id> {
+    DATABASE.regions_for(code)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::country::{self, Id, LANGUAGES, NAMES};
+    use crate::metadata::DATABASE;
+
+    #[test]
+    fn example_numbers() {
+        let code = |value| country::Code {
+            value,
+            source: country::Source::Plus,
+        };
+
+        let nanpa = code(1).example_numbers(&DATABASE);
+        assert_eq!(DATABASE.by_code(&1).unwrap().len(), nanpa.len());
+        assert!(nanpa.iter().all(|n| n.code().value() == 1));
+        assert!(nanpa.iter().all(|n| n.is_valid()));
+
+        let regions = nanpa
+            .iter()
+            .filter_map(|n| n.country().id())
+            .collect::<Vec<_>>();
+        assert!(regions.contains(&country::US));
+        assert!(regions.contains(&country::CA));
+
+        let gb = code(44).example_numbers(&DATABASE);
+        assert!(gb.iter().any(|n| n.country().id() == Some(country::GB)));
+
+        assert!(code(999).example_numbers(&DATABASE).is_empty());
+    }
+
+    #[test]
+    fn names() {
+        for (index, &(id, _)) in NAMES.iter().enumerate() {
+            assert_eq!(index, id as usize, "{id:?} is out of order");
+        }
+
+        assert_eq!("New Zealand", country::NZ.name());
+        assert_eq!("United Kingdom", country::GB.name());
+        assert_eq!("Zimbabwe", country::ZW.name());
+    }
+
+    #[test]
+    fn primary_language() {
+        for (index, &(id, _)) in LANGUAGES.iter().enumerate() {
+            assert_eq!(index, id as usize, "{id:?} is out of order");
+        }
+
+        assert_eq!("en", country::US.primary_language());
+        assert_eq!("fr", country::FR.primary_language());
+        assert_eq!("es", country::MX.primary_language());
+        assert_eq!("zh", country::TW.primary_language());
+    }
+
+    #[test]
+    fn all() {
+        assert_eq!(NAMES.len(), Id::all().count());
+
+        for (id, &(expected, _)) in Id::all().zip(NAMES.iter()) {
+            assert_eq!(expected, id);
+        }
+    }
+
+    #[test]
+    fn from_name() {
+        assert_eq!(Some(country::NZ), Id::from_name("New Zealand"));
+        assert_eq!(Some(country::NZ), Id::from_name("new zealand"));
+        assert_eq!(Some(country::BL), Id::from_name("St. Barthélemy"));
+        assert_eq!(None, Id::from_name("Atlantis"));
+
+        for &(id, name) in NAMES.iter() {
+            assert_eq!(Some(id), Id::from_name(name));
+        }
+    }
+}
@@ -49,15 +49,22 @@ pub enum Metadata {
 
 /// Parsing errors.
 #[derive(Error, Clone, Debug)]
+#[allow(unused)] // This is unused in the build script
 pub enum Parse {
     /// This generally indicates the string passed in had less than 3 digits in
-    /// it.
+    /// it, see `EmptyInput` for strings without any characters.
     #[error("not a number")]
     #[allow(unused)] // This is unused in the build script
     NoNumber,
 
+    /// The string passed in was empty, or only contained whitespace.
+    #[error("empty input")]
+    #[allow(unused)] // This is unused in the build script
+    EmptyInput,
+
     /// The country code supplied did not belong to a supported country or
-    /// non-geographical entity.
+    /// non-geographical entity. This is also the case for country codes
+    /// starting with a 0, which are never valid.
     #[error("invalid country code")]
     #[allow(unused)] // This is unused in the build script
     InvalidCountryCode,
@@ -81,11 +88,43 @@ pub enum Parse {
     #[allow(unused)] // This is unused in the build script
     TooLong,
 
+    /// This indicates the number was required to be in international format
+    /// with a leading plus, but it was not.
+    #[error("the number does not start with a plus")]
+    #[allow(unused)] // This is unused in the build script
+    MissingPlus,
+
+    /// The input contains a number, but it is malformed starting at the given
+    /// byte offset into the input.
+    #[error("malformed number at offset {offset}")]
+    #[allow(unused)] // This is unused in the build script
+    Malformed { offset: usize },
+
+    /// The extension is longer than 40 digits, or contains characters other
+    /// than digits and visual separators (or dial characters, for
+    /// `Extension::parse`).
+    #[error("invalid extension")]
+    #[allow(unused)] // This is unused in the build script
+    InvalidExtension,
+
     /// A integer parts of a number is malformed, normally this should be caught by the parsing regexes.
     #[error("malformed integer part in phone number: {0}")]
     MalformedInteger(#[from] std::num::ParseIntError),
 }
 
+/// Errors when parsing a phone number that has to be valid.
+#[derive(Error, Clone, Debug)]
+#[allow(unused)] // This is unused in the build script
+pub enum ParseOrInvalid {
+    /// The number could not be parsed.
+    #[error("{0}")]
+    Parse(#[from] Parse),
+
+    /// The number was parsed, but it is not valid.
+    #[error("the number is not valid")]
+    Invalid,
+}
+
 /// Loading of Database) Error
 #[derive(Error, Debug)]
 pub enum LoadMetadata {
@@ -117,3 +156,32 @@ pub enum LoadMetadata {
     #[error("Malformed Regex: {0}")]
     Regex(#[from] regex::Error),
 }
+
+#[cfg(test)]
+mod test {
+    use super::{LoadMetadata, Parse};
+    use std::error::Error;
+
+    #[test]
+    fn source() {
+        let err = Parse::from("x".parse::<u64>().unwrap_err());
+        assert!(err
+            .source()
+            .and_then(|e| e.downcast_ref::<std::num::ParseIntError>())
+            .is_some());
+        assert!(Parse::NoNumber.source().is_none());
+
+        let pattern = String::from("(");
+        let err = LoadMetadata::from(regex::Regex::new(&pattern).unwrap_err());
+        assert!(err
+            .source()
+            .and_then(|e| e.downcast_ref::<regex::Error>())
+            .is_some());
+
+        let err = LoadMetadata::from(std::io::Error::from(std::io::ErrorKind::NotFound));
+        assert!(err
+            .source()
+            .and_then(|e| e.downcast_ref::<std::io::Error>())
+            .is_some());
+    }
+}
@@ -12,12 +12,14 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::consts;
+use crate::error;
 use serde_derive::{Deserialize, Serialize};
 use std::fmt;
 use std::ops::Deref;
 
 /// A phone number extension.
-#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Hash, Debug)]
+#[derive(Clone, Eq, PartialEq, Ord, PartialOrd, Serialize, Deserialize, Hash, Debug)]
 pub struct Extension(pub(crate) String);
 
 impl<T: Into<String>> From<T> for Extension {
@@ -26,6 +28,35 @@ impl<T: Into<String>> From<T> for Extension {
     }
 }
 
+impl Extension {
+    /// Parse a dialable extension.
+    ///
+    /// Unlike `From`, this only accepts digits and the `,` (pause) and `;`
+    /// (wait) dial characters, with at most 40 digits.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::Extension;
+    ///
+    /// assert_eq!("123,4", &*Extension::parse("123,4").unwrap());
+    /// assert!(Extension::parse("12a").is_err());
+    /// ```
+    pub fn parse(value: &str) -> Result<Extension, error::Parse> {
+        if !value.chars().any(|c| c.is_ascii_digit())
+            || !value
+                .chars()
+                .all(|c| c.is_ascii_digit() || c == ',' || c == ';')
+            || value.chars().filter(|c| c.is_ascii_digit()).count()
+                > consts::MAX_LENGTH_FOR_EXTENSION
+        {
+            return Err(error::Parse::InvalidExtension);
+        }
+
+        Ok(Extension(value.into()))
+    }
+}
+
 impl Deref for Extension {
     type Target = str;
 
@@ -45,3 +76,26 @@ impl fmt::Display for Extension {
         write!(f, "{}", self.0)
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::extension::Extension;
+
+    #[test]
+    fn parse() {
+        assert_eq!(Extension::from("1234"), Extension::parse("1234").unwrap());
+        assert_eq!(
+            Extension::from("12,34;5"),
+            Extension::parse("12,34;5").unwrap()
+        );
+        assert!(Extension::parse(&"1".repeat(40)).is_ok());
+
+        assert!(Extension::parse("").is_err());
+        assert!(Extension::parse(",;").is_err());
+        assert!(Extension::parse("12a").is_err());
+        assert!(Extension::parse("12-34").is_err());
+        assert!(Extension::parse("12 34").is_err());
+        assert!(Extension::parse("\u{ff11}\u{ff12}").is_err());
+        assert!(Extension::parse(&"1".repeat(41)).is_err());
+    }
+}
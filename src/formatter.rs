@@ -17,10 +17,17 @@ use crate::{
     metadata::{Database, Format, Metadata, DATABASE},
     phone_number::PhoneNumber,
 };
+use serde_derive::{Deserialize, Serialize};
 use std::{borrow::Cow, fmt};
+use strum::{Display, EnumString};
 
 /// Formatting modes for phone number.
-#[derive(Copy, Clone, Eq, PartialEq, Debug)]
+///
+/// Modes are displayed as their lowercase name (e.g. `"e164"` or
+/// `"national"`), and parsed from it ignoring ASCII case.
+#[derive(Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Debug, Display, EnumString)]
+#[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "lowercase", ascii_case_insensitive)]
 pub enum Mode {
     /// E.164 formatting, no spaces, no decorations.
     E164,
@@ -34,6 +41,26 @@ pub enum Mode {
 
     /// RFC3966 formatting, see the RFC.
     Rfc3966,
+
+    /// The national significant number, including any significant leading
+    /// zeros, without grouping, prefixes or extension.
+    #[strum(serialize = "national_raw")]
+    NationalRaw,
+
+    /// ITU-T E.123 international notation, like `International` but with the
+    /// groups of digits separated by spaces only (e.g. `"+1 650 253 0000"`
+    /// instead of `"+1 650-253-0000"`).
+    E123,
+
+    /// ITU-T E.123 national notation, like `National` but with the groups of
+    /// digits separated by spaces only, and the national prefix and area code
+    /// in parentheses (e.g. `"(020) 7031 3000"` instead of `"020 7031 3000"`,
+    /// or `"(650) 253 0000"` instead of `"(650) 253-0000"`).
+    ///
+    /// The area code is the first group of the national format, unless the
+    /// format already puts it in parentheses.
+    #[strum(serialize = "e123_national")]
+    E123National,
 }
 
 /// A formatter for a `PhoneNumber`.
@@ -43,6 +70,12 @@ pub struct Formatter<'n, 'd, 'f> {
     database: Option<&'d Database>,
     mode: Mode,
     format: Option<&'f Format>,
+    rtl_safe: bool,
+    country_code_width: usize,
+    extension: bool,
+    extension_prefix: Option<&'f str>,
+    phone_context: Option<&'f str>,
+    compact: bool,
 }
 
 impl<'n, 'd, 'f> Formatter<'n, 'd, 'f> {
@@ -53,6 +86,12 @@ impl<'n, 'd, 'f> Formatter<'n, 'd, 'f> {
             database: Some(database),
             mode: self.mode,
             format: self.format,
+            rtl_safe: self.rtl_safe,
+            country_code_width: self.country_code_width,
+            extension: self.extension,
+            compact: self.compact,
+            extension_prefix: self.extension_prefix,
+            phone_context: self.phone_context,
         }
     }
 
@@ -62,56 +101,161 @@ impl<'n, 'd, 'f> Formatter<'n, 'd, 'f> {
         self
     }
 
-    /// Define a custom `Format` to use for formatting.
-    pub fn with<'a>(self, format: &'a Format) -> Formatter<'n, 'd, 'a> {
+    /// Wrap the output in Unicode left-to-right isolate marks, so the number is
+    /// not displayed reversed when embedded in right-to-left text.
+    ///
+    /// Disabled by default.
+    pub fn rtl_safe(mut self, rtl_safe: bool) -> Formatter<'n, 'd, 'f> {
+        self.rtl_safe = rtl_safe;
+        self
+    }
+
+    /// Left-pad the country code with zeros to the given width (e.g. `+001`),
+    /// for fixed-width interchange formats.
+    ///
+    /// Defaults to no padding.
+    pub fn country_code_width(mut self, width: usize) -> Formatter<'n, 'd, 'f> {
+        self.country_code_width = width;
+        self
+    }
+
+    /// Include the extension, if the number has one, in the modes that support
+    /// it.
+    ///
+    /// Enabled by default.
+    pub fn extension(mut self, extension: bool) -> Formatter<'n, 'd, 'f> {
+        self.extension = extension;
+        self
+    }
+
+    /// Omit the space between the country code and the rest of the number in
+    /// the `International` mode (e.g. `+4420 7031 3000`).
+    ///
+    /// Disabled by default.
+    pub fn compact(mut self, compact: bool) -> Formatter<'n, 'd, 'f> {
+        self.compact = compact;
+        self
+    }
+
+    /// Use the given separator between the number and its extension (e.g.
+    /// `" x"`) in every mode but `Rfc3966`, which always uses `;ext=`.
+    ///
+    /// Defaults to the preferred extension prefix of the country, or `" ext. "`.
+    pub fn extension_prefix<'a>(self, prefix: &'a str) -> Formatter<'n, 'd, 'a>
+    where
+        'f: 'a,
+    {
         Formatter {
             number: self.number,
             database: self.database,
             mode: self.mode,
-            format: Some(format),
+            format: self.format,
+            rtl_safe: self.rtl_safe,
+            country_code_width: self.country_code_width,
+            extension: self.extension,
+            compact: self.compact,
+            extension_prefix: Some(prefix),
+            phone_context: self.phone_context,
         }
     }
-}
 
-/// Create a new `Formatter` for the given phone number.
-pub fn format(number: &PhoneNumber) -> Formatter<'_, 'static, 'static> {
-    Formatter {
-        number,
-        database: None,
-        mode: Mode::E164,
-        format: None,
+    /// Format the number as a local number only valid within the given domain
+    /// name (e.g. `"example.com"`) in the `Rfc3966` mode, that is its national
+    /// format followed by a `phone-context` parameter, as described in section
+    /// 5.1.5 of RFC3966.
+    ///
+    /// Defaults to formatting a global number, which needs no context.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::Mode;
+    ///
+    /// let number = phonenumber::parse(None, "+64 3 331 6005").unwrap();
+    /// assert_eq!(
+    ///     "tel:03-331-6005;phone-context=example.com",
+    ///     number
+    ///         .format()
+    ///         .mode(Mode::Rfc3966)
+    ///         .phone_context("example.com")
+    ///         .to_string()
+    /// );
+    /// ```
+    pub fn phone_context<'a>(self, domain: &'a str) -> Formatter<'n, 'd, 'a>
+    where
+        'f: 'a,
+    {
+        Formatter {
+            number: self.number,
+            database: self.database,
+            mode: self.mode,
+            format: self.format,
+            rtl_safe: self.rtl_safe,
+            country_code_width: self.country_code_width,
+            extension: self.extension,
+            compact: self.compact,
+            extension_prefix: self.extension_prefix,
+            phone_context: Some(domain),
+        }
     }
-}
 
-/// Create a new `Formatter` for the given phone number using the given
-/// metadata database.
-pub fn format_with<'d, 'n>(
-    database: &'d Database,
-    number: &'n PhoneNumber,
-) -> Formatter<'n, 'd, 'static> {
-    Formatter {
-        number,
-        database: Some(database),
-        mode: Mode::E164,
-        format: None,
+    /// Define a custom `Format` to use for formatting.
+    pub fn with<'a>(self, format: &'a Format) -> Formatter<'n, 'd, 'a>
+    where
+        'f: 'a,
+    {
+        Formatter {
+            number: self.number,
+            database: self.database,
+            mode: self.mode,
+            format: Some(format),
+            rtl_safe: self.rtl_safe,
+            country_code_width: self.country_code_width,
+            extension: self.extension,
+            compact: self.compact,
+            extension_prefix: self.extension_prefix,
+            phone_context: self.phone_context,
+        }
     }
-}
 
-impl<'n, 'd, 'f> fmt::Display for Formatter<'n, 'd, 'f> {
-    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+    /// Write the formatted phone number into the given writer.
+    ///
+    /// This is what `Display` does, without going through a `String` when
+    /// writing into something else.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::Mode;
+    ///
+    /// let number = phonenumber::parse(None, "+44 20 7031 3000").unwrap();
+    /// let mut buffer = String::new();
+    ///
+    /// number.format().mode(Mode::National).write_to(&mut buffer).unwrap();
+    /// assert_eq!("020 7031 3000", buffer);
+    /// ```
+    pub fn write_to<W: fmt::Write + ?Sized>(&self, f: &mut W) -> fmt::Result {
         let db = self.database.unwrap_or(&DATABASE);
 
         // If the country code is invalid, return an error.
         let meta = db
-            .by_code(&self.number.country().code())
-            .and_then(|m| m.into_iter().next())
+            .main_metadata(self.number.country().code())
             .ok_or(fmt::Error)?;
 
-        let national = self.number.national().to_string();
+        // Numbers dialled within their own country, or domain.
+        let local = self.mode == Mode::National
+            || self.mode == Mode::E123National
+            || (self.mode == Mode::Rfc3966 && self.phone_context.is_some());
+
+        let national = self.number.national.digits();
+        let national = &*national;
+        let code = Code(self.number.country().code(), self.country_code_width);
+        // Regions without explicit international formats share the national
+        // ones, whose national prefix rules only ever apply to local modes.
         let formatter = self.format.or_else(|| {
             formatter(
-                &national,
-                if meta.international_formats().is_empty() || self.mode == Mode::National {
+                national,
+                if meta.international_formats().is_empty() || local {
                     meta.formats()
                 } else {
                     meta.international_formats()
@@ -119,92 +263,224 @@ impl<'n, 'd, 'f> fmt::Display for Formatter<'n, 'd, 'f> {
             )
         });
 
+        let extension_prefix = self
+            .extension_prefix
+            .or_else(|| meta.preferred_extension_prefix())
+            .unwrap_or(" ext. ");
+
+        // The national format, with the national prefix or carrier code.
+        let national_format = || match formatter {
+            Some(formatter) => {
+                let carrier = self
+                    .number
+                    .carrier()
+                    .and_then(|c| formatter.domestic_carrier().map(|f| (c, f)));
+
+                if let Some((carrier, format)) = carrier {
+                    replace(national, meta, formatter, Some(format), Some(carrier))
+                } else if let Some(prefix) = formatter.national_prefix() {
+                    replace(national, meta, formatter, Some(prefix), None)
+                } else {
+                    replace(national, meta, formatter, None, None)
+                }
+            }
+
+            None => national.to_owned(),
+        };
+
+        // The international format, never carrying the national prefix or
+        // carrier code, even when sharing the national formats.
+        let international_format = || match formatter {
+            Some(formatter) => replace(national, meta, formatter, None, None),
+            None => national.to_owned(),
+        };
+
+        if self.rtl_safe {
+            write!(f, "\u{2066}")?;
+        }
+
         match self.mode {
             // Requires no formatting at all, easy life.
             Mode::E164 => {
-                write!(f, "+{}{}", self.number.country().code(), national)?;
+                write!(f, "+{}{}", code, national)?;
             }
 
             // Space separated formatting with national specific rules.
             Mode::International => {
-                write!(f, "+{} ", self.number.country().code())?;
+                write!(f, "+{}", code)?;
 
-                if let Some(formatter) = formatter {
-                    write!(f, "{}", replace(&national, meta, formatter, None, None))?;
-                } else {
-                    write!(f, "{}", national)?;
+                if !self.compact {
+                    write!(f, " ")?;
                 }
 
-                if let Some(ext) = self.number.extension() {
-                    write!(
-                        f,
-                        "{}{}",
-                        meta.preferred_extension_prefix().unwrap_or(" ext. "),
-                        ext
-                    )?;
+                write!(f, "{}", international_format())?;
+
+                if let Some(ext) = self.number.extension().filter(|_| self.extension) {
+                    write!(f, "{}{}", extension_prefix, ext)?;
                 }
             }
 
             Mode::National => {
-                if let Some(formatter) = formatter {
-                    let carrier = self
-                        .number
-                        .carrier()
-                        .and_then(|c| formatter.domestic_carrier().map(|f| (c, f)));
-
-                    if let Some((carrier, format)) = carrier {
-                        write!(
-                            f,
-                            "{}",
-                            replace(&national, meta, formatter, Some(format), Some(carrier))
-                        )?;
-                    } else if let Some(prefix) = formatter.national_prefix() {
-                        write!(
-                            f,
-                            "{}",
-                            replace(&national, meta, formatter, Some(prefix), None)
-                        )?;
-                    } else {
-                        write!(f, "{}", replace(&national, meta, formatter, None, None))?;
+                write!(f, "{}", national_format())?;
+
+                if let Some(ext) = self.number.extension().filter(|_| self.extension) {
+                    write!(f, "{}{}", extension_prefix, ext)?;
+                }
+            }
+
+            Mode::NationalRaw => {
+                write!(f, "{}", national)?;
+            }
+
+            Mode::E123 => {
+                write!(f, "+{} ", code)?;
+
+                write!(
+                    f,
+                    "{}",
+                    consts::SEPARATOR_PATTERN.replace_all(&international_format(), " ")
+                )?;
+
+                if let Some(ext) = self.number.extension().filter(|_| self.extension) {
+                    write!(f, "{}{}", extension_prefix, ext)?;
+                }
+            }
+
+            Mode::E123National => {
+                let national = national_format();
+                let spaced = |groups: &str| {
+                    consts::SEPARATOR_PATTERN
+                        .replace_all(groups, " ")
+                        .trim()
+                        .to_owned()
+                };
+
+                // Formats already putting the area code in parentheses keep
+                // any national prefix before it in them.
+                let (first, rest) = match national.find(')') {
+                    Some(end) => (spaced(&national[..end]), spaced(&national[end + 1..])),
+
+                    None => {
+                        let groups = spaced(&national);
+
+                        match groups.split_once(' ') {
+                            Some((first, rest)) => (first.to_owned(), rest.to_owned()),
+                            None => (String::new(), groups),
+                        }
                     }
+                };
+
+                if first.is_empty() || rest.is_empty() {
+                    write!(f, "{}{}", first, rest)?;
                 } else {
-                    write!(f, "{}", national)?;
+                    write!(f, "({}) {}", first, rest)?;
+                }
+
+                if let Some(ext) = self.number.extension().filter(|_| self.extension) {
+                    write!(f, "{}{}", extension_prefix, ext)?;
+                }
+            }
+
+            Mode::Rfc3966 if self.phone_context.is_some() => {
+                write!(
+                    f,
+                    "tel:{}",
+                    consts::SEPARATOR_PATTERN
+                        .replace_all(&national_format(), "-")
+                        .trim_matches('-')
+                )?;
+
+                if let Some(subaddress) = self.number.subaddress() {
+                    write!(f, "{}{}", consts::RFC3966_ISDN_SUBADDRESS, subaddress)?;
+                }
+
+                if let Some(ext) = self.number.extension().filter(|_| self.extension) {
+                    write!(f, "{}{}", consts::RFC3966_EXTN_PREFIX, ext)?;
                 }
 
-                if let Some(ext) = self.number.extension() {
-                    write!(
-                        f,
-                        "{}{}",
-                        meta.preferred_extension_prefix().unwrap_or(" ext. "),
-                        ext
-                    )?;
+                if let Some(context) = self.phone_context {
+                    write!(f, "{}{}", consts::RFC3966_PHONE_CONTEXT, context)?;
                 }
             }
 
             Mode::Rfc3966 => {
-                write!(f, "tel:+{}-", self.number.country().code())?;
-
-                if let Some(formatter) = formatter {
-                    write!(
-                        f,
-                        "{}",
-                        consts::SEPARATOR_PATTERN
-                            .replace_all(&replace(&national, meta, formatter, None, None), "-")
-                    )?;
-                } else {
-                    write!(f, "{}", national)?;
+                write!(f, "tel:+{}-", code)?;
+
+                write!(
+                    f,
+                    "{}",
+                    consts::SEPARATOR_PATTERN.replace_all(&international_format(), "-")
+                )?;
+
+                if let Some(subaddress) = self.number.subaddress() {
+                    write!(f, "{}{}", consts::RFC3966_ISDN_SUBADDRESS, subaddress)?;
                 }
 
-                if let Some(ext) = self.number.extension() {
-                    write!(f, ";ext={}", ext)?;
+                if let Some(ext) = self.number.extension().filter(|_| self.extension) {
+                    write!(f, "{}{}", consts::RFC3966_EXTN_PREFIX, ext)?;
                 }
             }
         }
 
+        if self.rtl_safe {
+            write!(f, "\u{2069}")?;
+        }
+
         Ok(())
     }
 }
 
+/// A country code padded with zeros to the given width.
+struct Code(u16, usize);
+
+impl fmt::Display for Code {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:0width$}", self.0, width = self.1)
+    }
+}
+
+/// Create a new `Formatter` for the given phone number.
+pub fn format(number: &PhoneNumber) -> Formatter<'_, 'static, 'static> {
+    Formatter {
+        number,
+        database: None,
+        mode: Mode::E164,
+        format: None,
+        rtl_safe: false,
+        country_code_width: 0,
+        extension: true,
+        compact: false,
+        extension_prefix: None,
+        phone_context: None,
+    }
+}
+
+/// Create a new `Formatter` for the given phone number using the given
+/// metadata database.
+pub fn format_with<'d, 'n>(
+    database: &'d Database,
+    number: &'n PhoneNumber,
+) -> Formatter<'n, 'd, 'static> {
+    Formatter {
+        number,
+        database: Some(database),
+        mode: Mode::E164,
+        format: None,
+        rtl_safe: false,
+        country_code_width: 0,
+        extension: true,
+        compact: false,
+        extension_prefix: None,
+        phone_context: None,
+    }
+}
+
+impl<'n, 'd, 'f> fmt::Display for Formatter<'n, 'd, 'f> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        self.write_to(f)
+    }
+}
+
 fn formatter<'a>(number: &str, formats: &'a [Format]) -> Option<&'a Format> {
     for format in formats {
         let leading = format.leading_digits();
@@ -263,6 +539,7 @@ fn replace(
 mod test {
     use crate::country;
     use crate::formatter::Mode;
+    use crate::metadata::DATABASE;
     use crate::parser;
 
     #[test]
@@ -331,6 +608,349 @@ mod test {
         );
     }
 
+    #[test]
+    fn rtl_safe() {
+        let number = parser::parse(Some(country::GB), "+44 2070313000").unwrap();
+
+        assert_eq!(
+            "\u{2066}+44 20 7031 3000\u{2069}",
+            number
+                .format()
+                .mode(Mode::International)
+                .rtl_safe(true)
+                .to_string()
+        );
+        assert_eq!(
+            "+44 20 7031 3000",
+            number
+                .format()
+                .mode(Mode::International)
+                .rtl_safe(false)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn country_code_width() {
+        let number = parser::parse(Some(country::US), "+1 650 253 0000").unwrap();
+
+        assert_eq!("+16502530000", number.format().to_string());
+        assert_eq!(
+            "+0016502530000",
+            number.format().country_code_width(3).to_string()
+        );
+        assert_eq!(
+            "+001 650-253-0000",
+            number
+                .format()
+                .mode(Mode::International)
+                .country_code_width(3)
+                .to_string()
+        );
+        assert_eq!(
+            "tel:+001-650-253-0000",
+            number
+                .format()
+                .mode(Mode::Rfc3966)
+                .country_code_width(3)
+                .to_string()
+        );
+        assert_eq!(
+            "(650) 253-0000",
+            number
+                .format()
+                .mode(Mode::National)
+                .country_code_width(3)
+                .to_string()
+        );
+
+        // Codes wider than the requested width are left untouched.
+        let number = parser::parse(None, "+44 2070313000").unwrap();
+        assert_eq!(
+            "+442070313000",
+            number.format().country_code_width(1).to_string()
+        );
+    }
+
+    #[test]
+    fn mode() {
+        for mode in [
+            Mode::E164,
+            Mode::International,
+            Mode::National,
+            Mode::Rfc3966,
+            Mode::NationalRaw,
+            Mode::E123,
+            Mode::E123National,
+        ] {
+            assert_eq!(Ok(mode), mode.to_string().parse());
+        }
+
+        assert_eq!("e164", Mode::E164.to_string());
+        assert_eq!("rfc3966", Mode::Rfc3966.to_string());
+        assert_eq!("national_raw", Mode::NationalRaw.to_string());
+        assert_eq!("e123_national", Mode::E123National.to_string());
+        assert_eq!(Ok(Mode::National), "National".parse());
+        assert_eq!(Ok(Mode::International), "INTERNATIONAL".parse());
+        assert!("local".parse::<Mode>().is_err());
+    }
+
+    #[test]
+    fn e123() {
+        for (number, international, e123) in [
+            ("+1 650 253 0000", "+1 650-253-0000", "+1 650 253 0000"),
+            ("+44 20 7031 3000", "+44 20 7031 3000", "+44 20 7031 3000"),
+            ("+49 30 1234567", "+49 30 1234567", "+49 30 1234567"),
+            ("+7 495 1234567", "+7 495 123-45-67", "+7 495 123 45 67"),
+            (
+                "+1 650 253 0000 ext. 12",
+                "+1 650-253-0000 ext. 12",
+                "+1 650 253 0000 ext. 12",
+            ),
+        ] {
+            let number = parser::parse(None, number).unwrap();
+
+            assert_eq!(
+                international,
+                number.format().mode(Mode::International).to_string()
+            );
+            assert_eq!(e123, number.format().mode(Mode::E123).to_string());
+        }
+    }
+
+    #[test]
+    fn e123_national() {
+        for (number, national, e123) in [
+            ("+1 650 253 0000", "(650) 253-0000", "(650) 253 0000"),
+            ("+44 20 7031 3000", "020 7031 3000", "(020) 7031 3000"),
+            ("+49 30 1234567", "030 1234567", "(030) 1234567"),
+            ("+7 495 123-45-67", "8 (495) 123-45-67", "(8 495) 123 45 67"),
+            ("+39 06 1234 5678", "06 1234 5678", "(06) 1234 5678"),
+            ("+64 3 331 6005", "03 331 6005", "(03) 331 6005"),
+            ("+800 1234 5678", "1234 5678", "(1234) 5678"),
+        ] {
+            let number = parser::parse(None, number).unwrap();
+
+            assert_eq!(national, number.format().mode(Mode::National).to_string());
+            assert_eq!(e123, number.format().mode(Mode::E123National).to_string());
+        }
+
+        // Numbers without any group are left alone.
+        let number = parser::parse(None, "+800 1234 5678").unwrap();
+        let format = crate::metadata::Format {
+            pattern: regex_cache::CachedRegex::new(DATABASE.caches()[0].clone(), "(\\d+)").unwrap(),
+            format: "$1".into(),
+            leading_digits: Vec::new(),
+            national_prefix: None,
+            national_prefix_optional: false,
+            domestic_carrier: None,
+        };
+        assert_eq!(
+            "12345678",
+            number
+                .format()
+                .mode(Mode::E123National)
+                .with(&format)
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn extension() {
+        let number = parser::parse(None, "+1 650 253 0000 ext. 123").unwrap();
+
+        for (mode, with, without) in [
+            (
+                Mode::International,
+                "+1 650-253-0000 ext. 123",
+                "+1 650-253-0000",
+            ),
+            (Mode::National, "(650) 253-0000 ext. 123", "(650) 253-0000"),
+            (
+                Mode::Rfc3966,
+                "tel:+1-650-253-0000;ext=123",
+                "tel:+1-650-253-0000",
+            ),
+            (Mode::E123, "+1 650 253 0000 ext. 123", "+1 650 253 0000"),
+            (
+                Mode::E123National,
+                "(650) 253 0000 ext. 123",
+                "(650) 253 0000",
+            ),
+            (Mode::E164, "+16502530000", "+16502530000"),
+        ] {
+            assert_eq!(with, number.format().mode(mode).to_string());
+            assert_eq!(
+                without,
+                number.format().mode(mode).extension(false).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn subaddress() {
+        let number = parser::parse(None, "tel:+1-520-878-2491;isub=1234;ext=123").unwrap();
+
+        assert_eq!(
+            "tel:+1-520-878-2491;isub=1234;ext=123",
+            number.format().mode(Mode::Rfc3966).to_string()
+        );
+        assert_eq!(
+            "+1 520-878-2491 ext. 123",
+            number.format().mode(Mode::International).to_string()
+        );
+
+        let round_trip = parser::parse(None, number.format().mode(Mode::Rfc3966).to_string());
+        assert_eq!(number, round_trip.unwrap());
+    }
+
+    #[test]
+    fn phone_context() {
+        for (country, number, expected) in [
+            (
+                country::NZ,
+                "+64 3 331 6005",
+                "tel:03-331-6005;phone-context=example.com",
+            ),
+            (
+                country::US,
+                "+1 650 253 0000 ext. 123",
+                "tel:650-253-0000;ext=123;phone-context=example.com",
+            ),
+        ] {
+            let number = parser::parse(None, number).unwrap();
+            let formatted = number
+                .format()
+                .mode(Mode::Rfc3966)
+                .phone_context("example.com")
+                .to_string();
+
+            assert_eq!(expected, formatted);
+            let parsed = parser::parse(Some(country), &formatted).unwrap();
+            assert_eq!(number.to_e164(), parsed.to_e164());
+            assert_eq!(number.extension(), parsed.extension());
+        }
+    }
+
+    #[test]
+    fn compact() {
+        for (number, expected) in [
+            ("+44 20 7031 3000", "+4420 7031 3000"),
+            ("+1 650 253 0000", "+1650-253-0000"),
+            ("+39 06 1234 5678", "+3906 1234 5678"),
+            ("+49 30 1234567", "+4930 1234567"),
+            ("+1 650 253 0000 ext. 12", "+1650-253-0000 ext. 12"),
+        ] {
+            let number = parser::parse(None, number).unwrap();
+            let international = number.format().mode(Mode::International).to_string();
+
+            assert_eq!(
+                expected,
+                number
+                    .format()
+                    .mode(Mode::International)
+                    .compact(true)
+                    .to_string()
+            );
+            assert_eq!(international.replacen(' ', "", 1), expected);
+
+            // Other modes are not affected.
+            assert_eq!(
+                number.format().mode(Mode::E123).to_string(),
+                number.format().mode(Mode::E123).compact(true).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn extension_prefix() {
+        let number = parser::parse(None, "+1 650 253 0000 ext. 123").unwrap();
+
+        assert_eq!(
+            "(650) 253-0000 x123",
+            number
+                .format()
+                .mode(Mode::National)
+                .extension_prefix(" x")
+                .to_string()
+        );
+        assert_eq!(
+            "+1 650-253-0000 x123",
+            number
+                .format()
+                .mode(Mode::International)
+                .extension_prefix(" x")
+                .to_string()
+        );
+        assert_eq!(
+            "tel:+1-650-253-0000;ext=123",
+            number
+                .format()
+                .mode(Mode::Rfc3966)
+                .extension_prefix(" x")
+                .to_string()
+        );
+
+        let prefix = String::from(" x");
+        assert_eq!(
+            "(650) 253-0000 x123",
+            number
+                .format()
+                .mode(Mode::National)
+                .extension_prefix(&prefix)
+                .to_string()
+        );
+
+        // Numbers without an extension are not affected.
+        let number = parser::parse(None, "+1 650 253 0000").unwrap();
+        assert_eq!(
+            "(650) 253-0000",
+            number
+                .format()
+                .mode(Mode::National)
+                .extension_prefix(" x")
+                .to_string()
+        );
+    }
+
+    #[test]
+    fn national_raw() {
+        for (country, number, expected) in [
+            (country::GB, "+44 20 7031 3000 ext. 123", "2070313000"),
+            (country::IT, "+39 06 1234 5678", "0612345678"),
+            (country::US, "+1 650 253 0000", "6502530000"),
+        ] {
+            let number = parser::parse(Some(country), number).unwrap();
+
+            assert_eq!(
+                expected,
+                number.format().mode(Mode::NationalRaw).to_string()
+            );
+            assert_eq!(
+                number.national().to_string(),
+                number.format().mode(Mode::NationalRaw).to_string()
+            );
+        }
+    }
+
+    #[test]
+    fn mode_serde() {
+        for mode in [
+            Mode::E164,
+            Mode::International,
+            Mode::National,
+            Mode::Rfc3966,
+            Mode::NationalRaw,
+            Mode::E123,
+            Mode::E123National,
+        ] {
+            let json = serde_json::to_string(&mode).unwrap();
+
+            assert_eq!(format!("\"{mode}\""), json);
+            assert_eq!(mode, serde_json::from_str::<Mode>(&json).unwrap());
+        }
+    }
+
     #[test]
     fn gb() {
         assert_eq!(
@@ -378,4 +998,85 @@ mod test {
                 .to_string()
         );
     }
+
+    #[test]
+    fn write_to() {
+        use std::fmt::Write;
+
+        let number = parser::parse(None, "+1 650 253 0000 ext. 123").unwrap();
+        let mut buffer = String::from("number: ");
+
+        for mode in [
+            Mode::E164,
+            Mode::International,
+            Mode::National,
+            Mode::NationalRaw,
+            Mode::E123,
+            Mode::E123National,
+            Mode::Rfc3966,
+        ] {
+            buffer.truncate(8);
+            number.format().mode(mode).write_to(&mut buffer).unwrap();
+            assert_eq!(format!("number: {}", number.format().mode(mode)), buffer);
+        }
+
+        // Writers can also be unsized.
+        let mut buffer = String::new();
+        let writer: &mut dyn Write = &mut buffer;
+        number.format().write_to(writer).unwrap();
+        assert_eq!("+16502530000", buffer);
+    }
+
+    #[test]
+    fn international_without_national_prefix() {
+        use crate::metadata;
+
+        // Fall back to the national formats, which carry a national prefix
+        // formatting rule.
+        let database = metadata::edited(|metadata| {
+            metadata.retain(|m| m.id.as_deref() == Some("GB"));
+            metadata[0].international_formats.clear();
+        });
+        let number = parser::parse_with(&database, None, "+44 20 7031 3000").unwrap();
+
+        assert_eq!(
+            "020 7031 3000",
+            number
+                .format_with(&database)
+                .mode(Mode::National)
+                .to_string()
+        );
+        assert_eq!(
+            "+44 20 7031 3000",
+            number
+                .format_with(&database)
+                .mode(Mode::International)
+                .to_string()
+        );
+        assert_eq!(
+            "+442070313000",
+            number.format_with(&database).mode(Mode::E164).to_string()
+        );
+
+        // No region leaks its national prefix into the international modes.
+        for (id, _, example) in DATABASE.all_example_numbers() {
+            let number = match parser::parse(Some(id), example) {
+                Ok(number) => number,
+                Err(_) => continue,
+            };
+            let e164 = number.format().mode(Mode::E164).to_string();
+
+            for mode in [Mode::International, Mode::E123, Mode::Rfc3966] {
+                let digits = number
+                    .format()
+                    .mode(mode)
+                    .to_string()
+                    .chars()
+                    .filter(char::is_ascii_digit)
+                    .collect::<String>();
+
+                assert_eq!(e164[1..], digits, "{:?} {}", mode, e164);
+            }
+        }
+    }
 }
@@ -0,0 +1,124 @@
+// Copyright (C) 2017 1aim GmbH
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! [`arbitrary`] support, to fuzz code taking a `PhoneNumber` without going
+//! through string parsing.
+
+use crate::country;
+use crate::extension::Extension;
+use crate::metadata::DATABASE;
+use crate::national_number::NationalNumber;
+use crate::phone_number::PhoneNumber;
+use crate::validator::DESCRIPTOR_TYPES;
+use arbitrary::{Arbitrary, Result, Unstructured};
+use once_cell::sync::Lazy;
+
+/// Every country code in the database, with the possible lengths of its
+/// national numbers.
+static CODES: Lazy<Vec<(u16, Vec<u16>)>> = Lazy::new(|| {
+    let mut codes = DATABASE
+        .iter()
+        .map(|meta| {
+            let mut lengths = DESCRIPTOR_TYPES
+                .iter()
+                .filter_map(|&kind| meta.descriptors().get(kind))
+                .flat_map(|desc| desc.possible_length().iter().copied())
+                .collect::<Vec<_>>();
+
+            lengths.sort_unstable();
+            lengths.dedup();
+
+            (meta.country_code(), lengths)
+        })
+        .collect::<Vec<_>>();
+
+    codes.sort();
+    codes.dedup_by_key(|(code, _)| *code);
+    codes
+});
+
+const SOURCES: [country::Source; 4] = [
+    country::Source::Plus,
+    country::Source::Idd,
+    country::Source::Number,
+    country::Source::Default,
+];
+
+/// Phone numbers with a country code from the database and a national number
+/// of one of its possible lengths.
+///
+/// The numbers are plausible, but not necessarily valid.
+impl<'a> Arbitrary<'a> for PhoneNumber {
+    fn arbitrary(u: &mut Unstructured<'a>) -> Result<Self> {
+        let (code, lengths) = u.choose(&CODES)?;
+        let length = match u.choose(lengths) {
+            Ok(&length) => length,
+            Err(_) => u.int_in_range(1..=17)?,
+        };
+
+        let national = digits(u, length)?;
+        let zeros = national[..national.len() - 1]
+            .bytes()
+            .take_while(|&c| c == b'0')
+            .count();
+
+        let extension = if u.ratio(1, 8)? {
+            let length = u.int_in_range(1..=7)?;
+            Some(Extension(digits(u, length)?))
+        } else {
+            None
+        };
+
+        Ok(PhoneNumber {
+            code: country::Code {
+                value: *code,
+                source: *u.choose(&SOURCES)?,
+            },
+            national: NationalNumber::new(national.parse().unwrap(), zeros as u8).unwrap(),
+            extension,
+            carrier: None,
+            subaddress: None,
+            groups: None,
+        })
+    }
+}
+
+/// A string of random decimal digits.
+fn digits(u: &mut Unstructured<'_>, length: u16) -> Result<String> {
+    (0..length)
+        .map(|_| Ok(char::from(b'0' + u.int_in_range(0..=9)?)))
+        .collect()
+}
+
+#[cfg(test)]
+mod test {
+    use crate::metadata::DATABASE;
+    use crate::phone_number::PhoneNumber;
+    use arbitrary::{Arbitrary, Unstructured};
+
+    #[test]
+    fn arbitrary() {
+        let data = (0..4096u32)
+            .map(|i| (i.wrapping_mul(2654435761) >> 13) as u8)
+            .collect::<Vec<_>>();
+        let mut u = Unstructured::new(&data);
+
+        for _ in 0..32 {
+            let number = PhoneNumber::arbitrary(&mut u).unwrap();
+            assert!(DATABASE.by_code(&number.code.value()).is_some());
+            assert!(number.national.to_string().len() <= 17);
+            assert!(!number.format().to_string().is_empty());
+        }
+    }
+}
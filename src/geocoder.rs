@@ -0,0 +1,166 @@
+// Copyright (C) 2017 1aim GmbH
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+use crate::metadata::DATABASE;
+use crate::phone_number::{PhoneNumber, Type};
+use bincode::Options;
+use once_cell::sync::Lazy;
+
+const GEOCODING: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/geocoding.bin"));
+
+/// Descriptions of number prefixes, sorted by prefix.
+type Prefixes = Vec<(u64, String)>;
+
+/// Prefix descriptions for every locale, sorted by locale.
+static DESCRIPTIONS: Lazy<Vec<(String, Prefixes)>> = Lazy::new(|| {
+    bincode::options()
+        .with_varint_encoding()
+        .deserialize(GEOCODING)
+        .unwrap()
+});
+
+/// Get a text description of the geographical area the given phone number
+/// belongs to, in the given locale (e.g. "en", "de" or "zh_Hant").
+///
+/// The description is the one of the longest prefix matching the number, and
+/// falls back to the English description, except for Chinese, Japanese and
+/// Korean, when the locale has none. Numbers that cannot be geocoded more
+/// precisely, such as most mobile numbers or toll-free numbers, are described
+/// by the English name of their country.
+///
+/// Returns `None` if the number is invalid or does not belong to a country.
+///
+/// # Example
+///
+/// ```
+/// use phonenumber::{self, geocoder};
+///
+/// let number = phonenumber::parse(None, "+1 650 253 0000").unwrap();
+/// assert_eq!(Some("Mountain View, CA".into()), geocoder::describe(&number, "en"));
+///
+/// let number = phonenumber::parse(None, "+44 7912 345678").unwrap();
+/// assert_eq!(Some("United Kingdom".into()), geocoder::describe(&number, "en"));
+/// ```
+pub fn describe(number: &PhoneNumber, locale: &str) -> Option<String> {
+    if !number.is_valid() {
+        return None;
+    }
+
+    let id = number.country().id()?;
+
+    match number.number_type(&DATABASE) {
+        Type::FixedLine | Type::Mobile | Type::FixedLineOrMobile => (),
+        _ => return Some(id.name().into()),
+    }
+
+    let digits = format!("{}{}", number.code().value(), number.national());
+    let description = lookup(locale, &digits).or_else(|| {
+        if may_fall_back_to_english(locale) {
+            lookup("en", &digits)
+        } else {
+            None
+        }
+    });
+
+    Some(description.unwrap_or_else(|| id.name()).into())
+}
+
+/// Find the description of the longest prefix of `digits` in the given
+/// locale, trying less specific locales (e.g. "zh" for "zh_Hant") when needed.
+fn lookup(locale: &str, digits: &str) -> Option<&'static str> {
+    let mut locale = locale.replace('-', "_");
+
+    loop {
+        if let Some(prefixes) = descriptions(&locale) {
+            for len in (1..=digits.len()).rev() {
+                let prefix = digits[..len].parse::<u64>().ok()?;
+
+                if let Ok(index) = prefixes.binary_search_by_key(&prefix, |&(p, _)| p) {
+                    return Some(&prefixes[index].1);
+                }
+            }
+        }
+
+        match locale.rfind('_') {
+            Some(index) => locale.truncate(index),
+            None => return None,
+        }
+    }
+}
+
+fn descriptions(locale: &str) -> Option<&'static [(u64, String)]> {
+    DESCRIPTIONS
+        .binary_search_by(|(l, _)| l.as_str().cmp(locale))
+        .ok()
+        .map(|index| &DESCRIPTIONS[index].1[..])
+}
+
+fn may_fall_back_to_english(locale: &str) -> bool {
+    let language = locale.split(['_', '-']).next().unwrap_or("");
+    language != "zh" && language != "ja" && language != "ko"
+}
+
+#[cfg(test)]
+mod test {
+    use crate::geocoder;
+    use crate::parser;
+
+    fn describe(number: &str, locale: &str) -> Option<String> {
+        geocoder::describe(&parser::parse(None, number).unwrap(), locale)
+    }
+
+    #[test]
+    fn describe_prefix() {
+        assert_eq!(
+            Some("Mountain View, CA".into()),
+            describe("+1 650 253 0000", "en")
+        );
+        assert_eq!(Some("London".into()), describe("+44 20 7031 3000", "en"));
+        assert_eq!(Some("Milan".into()), describe("+39 02 3661 8300", "en"));
+    }
+
+    #[test]
+    fn describe_locale() {
+        assert_eq!(
+            Some("Mountain View, CA".into()),
+            describe("+1 650 253 0000", "en-US")
+        );
+        assert_eq!(Some("Geneva".into()), describe("+41 22 345 67 89", "en"));
+        assert_eq!(Some("Genf".into()), describe("+41 22 345 67 89", "de"));
+        // No German description for this prefix, fall back to English.
+        assert_eq!(
+            Some("Mountain View, CA".into()),
+            describe("+1 650 253 0000", "de")
+        );
+    }
+
+    #[test]
+    fn describe_country() {
+        // Mobile numbers without prefix data and non geographic types.
+        assert_eq!(
+            Some("United Kingdom".into()),
+            describe("+44 7912 345678", "en")
+        );
+        assert_eq!(
+            Some("United States".into()),
+            describe("+1 800 253 0000", "en")
+        );
+    }
+
+    #[test]
+    fn describe_invalid() {
+        assert_eq!(None, describe("+1 2530000", "en"));
+        assert_eq!(None, describe("+800 12345678", "en"));
+    }
+}
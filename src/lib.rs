@@ -18,6 +18,7 @@
 use doc_comment::doctest;
 
 #[cfg(test)]
+#[allow(clippy::single_component_path_imports)]
 use rstest_reuse;
 
 #[cfg(test)]
@@ -25,15 +26,19 @@ doctest!("../README.md");
 
 /// Errors for various parts of the crate.
 mod error;
-pub use crate::error::{Metadata as MetadataError, Parse as ParseError};
+pub use crate::error::{
+    LoadMetadata as LoadMetadataError, Metadata as MetadataError, Parse as ParseError,
+    ParseOrInvalid,
+};
 
 /// Phone number metadata, containing patterns, formatting and other useful
 /// data about countries and phone numbers.
 pub mod metadata;
-pub use crate::metadata::Metadata;
+pub use crate::metadata::{set_default_database, Metadata};
 
 /// Country related types.
 pub mod country;
+pub use crate::country::{country_code_for, regions_for};
 
 mod consts;
 
@@ -50,10 +55,36 @@ mod phone_number;
 pub use crate::phone_number::{PhoneNumber, Type};
 
 mod parser;
-pub use crate::parser::{parse, parse_with};
+pub use crate::parser::{
+    guess_region, guess_region_with, parse, parse_all, parse_all_with, parse_e164, parse_e164_with,
+    parse_prefix, parse_prefix_with, parse_strict, parse_strict_with, parse_valid,
+    parse_valid_with, parse_with, parse_with_options, parse_with_regions, parse_with_regions_with,
+    ParseOptions,
+};
+#[cfg(feature = "rayon")]
+pub use crate::parser::{par_parse_all, par_parse_all_with};
 
 mod formatter;
 pub use crate::formatter::{format, format_with, Formatter, Mode};
 
 mod validator;
-pub use crate::validator::{is_valid, is_valid_with, is_viable, Validation};
+pub use crate::validator::{
+    can_be_internationally_dialled, can_be_internationally_dialled_with, can_dial_from,
+    can_dial_from_with, is_possible, is_possible_reason, is_possible_reason_with, is_possible_with,
+    is_valid, is_valid_detailed, is_valid_detailed_with, is_valid_for_region,
+    is_valid_for_region_with, is_valid_reason, is_valid_reason_with, is_valid_with, is_viable,
+    validate, validate_with, validation_detail, validation_detail_with, Validation,
+    ValidationDetail, ValidationIssue,
+};
+
+pub mod serde;
+
+#[cfg(feature = "proptest")]
+pub mod strategy;
+
+#[cfg(feature = "arbitrary")]
+mod fuzz;
+
+/// Offline geocoding of phone numbers.
+#[cfg(feature = "geocoder")]
+pub mod geocoder;
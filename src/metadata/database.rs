@@ -12,43 +12,90 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::consts;
+use crate::country;
 use crate::error;
 use crate::metadata::loader;
+use crate::phone_number::Type;
 use crate::Metadata;
 use bincode;
 use bincode::Options;
 use fnv::FnvHashMap;
-use once_cell::sync::Lazy;
+use once_cell::sync::{Lazy, OnceCell};
+use regex::RegexBuilder;
 use regex_cache::{CachedRegex, CachedRegexBuilder, RegexCache};
 use std::borrow::Borrow;
+use std::collections::hash_map::DefaultHasher;
 use std::fs::File;
-use std::hash::Hash;
+use std::hash::{Hash, Hasher};
 use std::io::{BufReader, Cursor};
 use std::path::Path;
 use std::sync::{Arc, Mutex};
 
 const DATABASE: &[u8] = include_bytes!(concat!(env!("OUT_DIR"), "/database.bin"));
 
-/// The Google provided metadata database, used as default.
-pub static DEFAULT: Lazy<Database> = Lazy::new(|| {
-    Database::from(
-        bincode::options()
-            .with_varint_encoding()
-            .deserialize(DATABASE)
-            .unwrap(),
-    )
-    .unwrap()
+static CUSTOM: OnceCell<Database> = OnceCell::new();
+
+/// Number of regular expression caches the database spreads its patterns
+/// over; every pattern is only ever matched under the lock of its own cache,
+/// so parsing from many threads doesn't serialize on a single lock.
+const CACHE_SHARDS: usize = 16;
+
+/// Capacity of every regular expression cache shard.
+const CACHE_CAPACITY: usize = 100;
+
+/// The metadata database used as default, this is the Google provided one
+/// unless another was set with [`set_default_database`].
+pub static DEFAULT: Lazy<&'static Database> = Lazy::new(|| {
+    CUSTOM.get_or_init(|| {
+        Database::from(
+            bincode::options()
+                .with_varint_encoding()
+                .deserialize(DATABASE)
+                .unwrap(),
+        )
+        .unwrap()
+    })
 });
 
+/// Replace the metadata database used by the functions not taking an explicit
+/// `Database`, like [`parse`](crate::parse) or [`is_valid`](crate::is_valid).
+///
+/// This has to be called before the default database is first used, otherwise
+/// the given database is handed back as error.
+///
+/// # Example
+///
+/// ```
+/// use phonenumber::metadata::Database;
+///
+/// let database = Database::load("assets/PhoneNumberMetadata.xml").unwrap();
+/// assert!(phonenumber::set_default_database(database).is_ok());
+///
+/// let number = phonenumber::parse(None, "+64 3 331 6005").unwrap();
+/// assert!(phonenumber::is_valid(&number));
+///
+/// let database = Database::load("assets/PhoneNumberMetadata.xml").unwrap();
+/// assert!(phonenumber::set_default_database(database).is_err());
+/// ```
+pub fn set_default_database(database: Database) -> Result<(), Database> {
+    CUSTOM.set(database)
+}
+
 /// Representation of a database of metadata for phone number.
 #[derive(Clone, Debug)]
 pub struct Database {
-    cache: Arc<Mutex<RegexCache>>,
+    caches: Box<[Arc<Mutex<RegexCache>>]>,
     by_id: FnvHashMap<String, Arc<super::Metadata>>,
     by_code: FnvHashMap<u16, Vec<Arc<super::Metadata>>>,
     regions: FnvHashMap<u16, Vec<String>>,
+    by_prefix: Arc<FnvHashMap<u16, Box<[Candidates]>>>,
 }
 
+/// The metadata entries a national number may belong to, in order, and
+/// whether it belongs to them for sure.
+type Candidates = Box<[(Arc<super::Metadata>, bool)]>;
+
 impl Database {
     /// Load a database from the given file.
     pub fn load<P: AsRef<Path>>(path: P) -> Result<Self, error::LoadMetadata> {
@@ -60,8 +107,39 @@ impl Database {
         Database::from(loader::load(Cursor::new(content.as_ref()))?)
     }
 
+    /// Load a database from the given file, compiling every regular
+    /// expression, see [`from_checked`](Self::from_checked).
+    pub fn load_checked<P: AsRef<Path>>(path: P) -> Result<Self, error::LoadMetadata> {
+        Database::from_checked(loader::load(BufReader::new(File::open(path)?))?)
+    }
+
+    /// Parse a database from the given string, compiling every regular
+    /// expression, see [`from_checked`](Self::from_checked).
+    pub fn parse_checked<S: AsRef<str>>(content: S) -> Result<Self, error::LoadMetadata> {
+        Database::from_checked(loader::load(Cursor::new(content.as_ref()))?)
+    }
+
     /// Create a database from a loaded database.
+    ///
+    /// Regular expressions are only checked for syntax errors, they're
+    /// compiled the first time they're used, which panics if they turn out to
+    /// be invalid anyway (e.g. too big).
     pub fn from(meta: Vec<loader::Metadata>) -> Result<Self, error::LoadMetadata> {
+        Database::build(meta, false)
+    }
+
+    /// Create a database from a loaded database, compiling every regular
+    /// expression so that any invalid one is returned as
+    /// [`LoadMetadataError::Regex`](crate::LoadMetadataError::Regex) instead
+    /// of panicking later on.
+    ///
+    /// This is slower than [`from`](Self::from), and meant for metadata that
+    /// may not be trusted, like user provided files.
+    pub fn from_checked(meta: Vec<loader::Metadata>) -> Result<Self, error::LoadMetadata> {
+        Database::build(meta, true)
+    }
+
+    fn build(meta: Vec<loader::Metadata>, check_regex: bool) -> Result<Self, error::LoadMetadata> {
         fn tranpose<T, E>(value: Option<Result<T, E>>) -> Result<Option<T>, E> {
             match value {
                 None => Ok(None),
@@ -72,8 +150,19 @@ impl Database {
             }
         }
 
-        let cache = Arc::new(Mutex::new(RegexCache::new(100)));
+        let caches = (0..CACHE_SHARDS)
+            .map(|_| Arc::new(Mutex::new(RegexCache::new(CACHE_CAPACITY))))
+            .collect::<Box<[_]>>();
+
         let regex = |value: String| -> Result<CachedRegex, error::LoadMetadata> {
+            let mut hasher = DefaultHasher::new();
+            value.hash(&mut hasher);
+            let cache = &caches[hasher.finish() as usize % caches.len()];
+
+            if check_regex {
+                RegexBuilder::new(&value).ignore_whitespace(true).build()?;
+            }
+
             Ok(CachedRegexBuilder::new(cache.clone(), &value)
                 .ignore_whitespace(true)
                 .build()?)
@@ -198,39 +287,174 @@ impl Database {
             })
         };
 
-        let mut by_id = FnvHashMap::default();
-        let mut by_code = FnvHashMap::default();
-        let mut regions = FnvHashMap::default();
+        let mut database = Database {
+            caches: caches.clone(),
+            by_id: FnvHashMap::default(),
+            by_code: FnvHashMap::default(),
+            regions: FnvHashMap::default(),
+            by_prefix: Arc::default(),
+        };
 
         for meta in meta {
-            let meta = Arc::new(metadata(meta)?);
+            database.insert(Arc::new(metadata(meta)?));
+        }
 
-            by_id.insert(meta.id.clone(), meta.clone());
+        database.index();
+        Ok(database)
+    }
 
-            let by_code = by_code.entry(meta.country_code).or_insert_with(Vec::new);
+    /// Add a metadata entry, replacing any previous entry of the same region.
+    fn insert(&mut self, meta: Arc<Metadata>) {
+        let previous = self.by_id.insert(meta.id.clone(), meta.clone());
+
+        // Regions are unique, so one given again with another country code is
+        // moved to that code. The non-geographical entities all share an ID,
+        // but not a code.
+        if let Some(previous) = previous.filter(|p| {
+            p.id != consts::REGION_CODE_FOR_NON_GEO_ENTITY && p.country_code != meta.country_code
+        }) {
+            if let Some(by_code) = self.by_code.get_mut(&previous.country_code) {
+                by_code.retain(|m| m.id != previous.id);
+            }
 
-            let regions = regions.entry(meta.country_code).or_insert_with(Vec::new);
+            if let Some(regions) = self.regions.get_mut(&previous.country_code) {
+                regions.retain(|id| id != &previous.id);
+            }
 
-            if meta.main_country_for_code {
-                by_code.insert(0, meta.clone());
-                regions.insert(0, meta.id.clone())
-            } else {
-                by_code.push(meta.clone());
-                regions.push(meta.id.clone());
+            if self
+                .by_code
+                .get(&previous.country_code)
+                .map_or(false, Vec::is_empty)
+            {
+                self.by_code.remove(&previous.country_code);
+                self.regions.remove(&previous.country_code);
             }
         }
 
-        Ok(Database {
-            cache: cache.clone(),
-            by_id,
-            by_code,
-            regions,
-        })
+        let by_code = self.by_code.entry(meta.country_code).or_default();
+        let regions = self.regions.entry(meta.country_code).or_default();
+
+        // A region given more than once replaces the previous entry, like in
+        // `by_id`, instead of being listed twice for its code.
+        by_code.retain(|m| m.id != meta.id);
+        regions.retain(|id| id != &meta.id);
+
+        if meta.main_country_for_code {
+            by_code.insert(0, meta.clone());
+            regions.insert(0, meta.id.clone())
+        } else {
+            by_code.push(meta.clone());
+            regions.push(meta.id.clone());
+        }
     }
 
-    /// Get the regular expression cache.
+    /// Create a copy of this database with the entries of the given overlay
+    /// replacing those of the same region, or of the same country code for
+    /// non-geographical entities.
+    ///
+    /// This allows patching the metadata of a few regions, without having to
+    /// provide the metadata of every other region.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::metadata::{Database, DATABASE};
+    ///
+    /// let overlay = Database::load("assets/PhoneNumberMetadata.xml").unwrap();
+    /// let database = DATABASE.with_overlay(overlay);
+    ///
+    /// assert_eq!(DATABASE.iter().count(), database.iter().count());
+    /// ```
+    pub fn with_overlay(&self, overlay: Database) -> Database {
+        let mut database = self.clone();
+
+        for meta in overlay.by_code.into_values().flatten() {
+            database.insert(meta);
+        }
+
+        database.index();
+        database
+    }
+
+    /// Index the regions of shared country codes by the first three digits of
+    /// the national numbers they may have, see [`candidates`](Self::candidates).
+    fn index(&mut self) {
+        let by_prefix = self
+            .by_code
+            .iter()
+            .filter(|(_, metas)| metas.len() > 1)
+            .map(|(&code, metas)| {
+                let prefixes = metas
+                    .iter()
+                    .map(|m| m.leading_digits.as_ref().and_then(|p| prefixes(p.as_str())))
+                    .collect::<Vec<_>>();
+
+                let table = (0..1000)
+                    .map(|prefix| {
+                        let mut candidates = Vec::new();
+
+                        for (meta, prefixes) in metas.iter().zip(&prefixes) {
+                            match prefixes.as_ref().map(|p| p[prefix]) {
+                                Some(None) => (),
+
+                                Some(Some(true)) => {
+                                    candidates.push((meta.clone(), true));
+                                    break;
+                                }
+
+                                _ => candidates.push((meta.clone(), false)),
+                            }
+                        }
+
+                        candidates.into_boxed_slice()
+                    })
+                    .collect();
+
+                (code, table)
+            })
+            .collect();
+
+        self.by_prefix = Arc::new(by_prefix);
+    }
+
+    /// Get the first regular expression cache.
+    ///
+    /// The metadata patterns are spread over several caches, so this one only
+    /// holds some of them, see [`caches`](Self::caches).
+    #[deprecated(note = "the patterns are spread over several caches, use `caches`")]
     pub fn cache(&self) -> Arc<Mutex<RegexCache>> {
-        self.cache.clone()
+        self.caches[0].clone()
+    }
+
+    /// Get the regular expression caches.
+    ///
+    /// Every metadata pattern is compiled and matched in one of these caches,
+    /// picked by a hash of the pattern, so patterns in different caches can be
+    /// matched from several threads at once. Each cache has its own capacity,
+    /// and any of them can be shared by additional regular expressions.
+    pub fn caches(&self) -> &[Arc<Mutex<RegexCache>>] {
+        &self.caches
+    }
+
+    /// Compile the most used regular expressions up front.
+    ///
+    /// Patterns are otherwise compiled the first time they are matched,
+    /// which makes the first parse or format for a country noticeably slower.
+    /// This compiles the general descriptor and the format patterns, with
+    /// their leading digits, of every country, so it's best called once at
+    /// startup.
+    pub fn warm_up(&self) {
+        for meta in self.iter() {
+            meta.descriptors().general().national_number().is_match("");
+
+            for format in meta.formats().iter().chain(meta.international_formats()) {
+                format.pattern().is_match("");
+
+                for leading in format.leading_digits() {
+                    leading.is_match("");
+                }
+            }
+        }
     }
 
     /// Get a metadata entry by country ID.
@@ -242,6 +466,21 @@ impl Database {
         self.by_id.get(key).map(AsRef::as_ref)
     }
 
+    /// Get the metadata of the given country.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::{country, metadata::DATABASE};
+    ///
+    /// let nz = DATABASE.metadata(country::NZ).unwrap();
+    /// assert_eq!(64, nz.country_code());
+    /// assert_eq!(Some("0"), nz.national_prefix());
+    /// ```
+    pub fn metadata(&self, id: country::Id) -> Option<&super::Metadata> {
+        self.by_id(id.as_ref())
+    }
+
     /// Get metadata entries by country code.
     pub fn by_code<Q>(&self, key: &Q) -> Option<Vec<&super::Metadata>>
     where
@@ -253,6 +492,68 @@ impl Database {
             .map(|m| m.iter().map(AsRef::as_ref).collect())
     }
 
+    /// Get the metadata of the main country of a country code, the first one
+    /// returned by [`by_code`](Self::by_code).
+    pub(crate) fn main_metadata(&self, code: u16) -> Option<&super::Metadata> {
+        self.by_code
+            .get(&code)
+            .and_then(|m| m.first())
+            .map(AsRef::as_ref)
+    }
+
+    /// Get the country code of the given country.
+    pub fn country_code_for(&self, id: country::Id) -> Option<u16> {
+        self.metadata(id).map(|m| m.country_code())
+    }
+
+    /// Get the metadata entries of the regions sharing the given country code
+    /// a national number may belong to, in order, and whether it belongs to
+    /// them for sure.
+    ///
+    /// This is `None` for country codes of a single region, and for national
+    /// numbers shorter than three digits.
+    pub(crate) fn candidates(
+        &self,
+        code: u16,
+        national: &str,
+    ) -> Option<&[(Arc<super::Metadata>, bool)]> {
+        let prefix = national.get(..3)?.parse::<usize>().ok()?;
+        self.by_prefix.get(&code).map(|table| &*table[prefix])
+    }
+
+    /// Split a number in E.164 format into its country code and national
+    /// significant number, without parsing it.
+    ///
+    /// This is `None` if the number isn't a plus followed only by digits, or
+    /// doesn't start with a country code of the database.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::metadata::DATABASE;
+    ///
+    /// assert_eq!(Some((44, "2070313000")), DATABASE.split_e164("+442070313000"));
+    /// assert_eq!(None, DATABASE.split_e164("+44 20 7031 3000"));
+    /// ```
+    pub fn split_e164<'a>(&self, number: &'a str) -> Option<(u16, &'a str)> {
+        let digits = number.strip_prefix('+')?;
+
+        // Country codes never start with a 0.
+        if digits.starts_with('0') || !digits.bytes().all(|b| b.is_ascii_digit()) {
+            return None;
+        }
+
+        (1..=consts::MAX_LENGTH_FOR_COUNTRY_CODE).find_map(|len| {
+            let code = digits.get(..len)?.parse::<u16>().ok()?;
+
+            if self.by_code.contains_key(&code) {
+                Some((code, &digits[len..]))
+            } else {
+                None
+            }
+        })
+    }
+
     /// Get all country IDs corresponding to the given country code.
     pub fn region<Q>(&self, code: &Q) -> Option<Vec<&str>>
     where
@@ -264,8 +565,458 @@ impl Database {
             .map(|m| m.iter().map(AsRef::as_ref).collect())
     }
 
+    /// Get all countries sharing the given country code, with the main country
+    /// for the code first.
+    ///
+    /// Non-geographical entities are not countries, so for their codes this is
+    /// empty.
+    pub fn regions_for(&self, code: u16) -> Vec<country::Id> {
+        let mut regions = self
+            .by_code(&code)
+            .unwrap_or_default()
+            .into_iter()
+            .filter_map(|m| {
+                m.id()
+                    .parse()
+                    .ok()
+                    .map(|id| (m.is_main_country_for_code(), id))
+            })
+            .collect::<Vec<_>>();
+
+        regions.sort_by_key(|&(main, _)| !main);
+        regions.into_iter().map(|(_, id)| id).collect()
+    }
+
     /// Iterator over all `Metadata` entries in this database.
     pub fn iter(&self) -> impl Iterator<Item = &Metadata> {
         self.by_id.values().map(AsRef::as_ref)
     }
+
+    /// Iterate over the example numbers of every country, in no particular
+    /// order, together with the country and the type of number they are an
+    /// example of.
+    ///
+    /// The examples are national significant numbers, without any formatting.
+    /// Non-geographical entities are skipped, since they have no country.
+    pub fn all_example_numbers(&self) -> impl Iterator<Item = (country::Id, Type, &str)> {
+        const TYPES: [Type; 15] = [
+            Type::FixedLine,
+            Type::Mobile,
+            Type::TollFree,
+            Type::PremiumRate,
+            Type::SharedCost,
+            Type::PersonalNumber,
+            Type::Voip,
+            Type::Pager,
+            Type::Uan,
+            Type::Emergency,
+            Type::Voicemail,
+            Type::ShortCode,
+            Type::StandardRate,
+            Type::Carrier,
+            Type::NoInternational,
+        ];
+
+        self.iter()
+            .filter_map(|meta| meta.id().parse().ok().map(|id| (id, meta)))
+            .flat_map(|(id, meta)| {
+                TYPES.iter().filter_map(move |&kind| {
+                    meta.descriptors()
+                        .get(kind)
+                        .and_then(|d| d.example())
+                        .map(|example| (id, kind, example))
+                })
+            })
+    }
+}
+
+/// The three digit prefixes of the national numbers a leading digits pattern
+/// matches, `Some(true)` if it matches every number with that prefix and
+/// `Some(false)` if only some of them.
+///
+/// Only alternations of digits and digit classes (e.g. `8001|8[024]9`) are
+/// analyzed, anything else is `None`.
+fn prefixes(pattern: &str) -> Option<Vec<Option<bool>>> {
+    let mut result = vec![None; 1000];
+
+    for alternative in pattern.split('|') {
+        let mut chars = alternative.chars().filter(|c| !c.is_whitespace());
+        let mut sets = Vec::new();
+
+        while let Some(c) = chars.next() {
+            sets.push(match c {
+                '[' => {
+                    let mut set = 0u16;
+                    let mut previous = None;
+
+                    loop {
+                        match chars.next()? {
+                            ']' => break,
+
+                            '-' => {
+                                let start = previous.take()?;
+                                let end = chars.next()?.to_digit(10)?;
+
+                                for digit in start..=end {
+                                    set |= 1 << digit;
+                                }
+                            }
+
+                            c => {
+                                let digit = c.to_digit(10)?;
+                                set |= 1 << digit;
+                                previous = Some(digit);
+                            }
+                        }
+                    }
+
+                    set
+                }
+
+                c => 1 << c.to_digit(10)?,
+            });
+        }
+
+        if sets.is_empty() {
+            return None;
+        }
+
+        for (prefix, entry) in result.iter_mut().enumerate() {
+            let digits = [prefix / 100, prefix / 10 % 10, prefix % 10];
+
+            if sets
+                .iter()
+                .zip(&digits)
+                .all(|(set, digit)| set & (1 << digit) != 0)
+            {
+                *entry = Some(entry.unwrap_or(false) || sets.len() <= 3);
+            }
+        }
+    }
+
+    Some(result)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::country;
+    use crate::error;
+    use crate::metadata::{self, Database, DATABASE};
+    use crate::phone_number::Type;
+    use crate::{parser, validator};
+
+    #[test]
+    fn warm_up() {
+        let database = Database::load("assets/PhoneNumberMetadata.xml").unwrap();
+        let compiled = |database: &Database| {
+            database
+                .caches()
+                .iter()
+                .map(|cache| cache.lock().unwrap().len())
+                .sum::<usize>()
+        };
+
+        assert_eq!(0, compiled(&database));
+        database.warm_up();
+        assert!(compiled(&database) > database.iter().count());
+
+        // The patterns are spread over every cache.
+        assert!(database
+            .caches()
+            .iter()
+            .all(|cache| !cache.lock().unwrap().is_empty()));
+    }
+
+    #[test]
+    fn country_code_for() {
+        assert_eq!(Some(1), DATABASE.country_code_for(country::US));
+        assert_eq!(Some(1), DATABASE.country_code_for(country::CA));
+        assert_eq!(Some(44), DATABASE.country_code_for(country::GB));
+        assert_eq!(Some(44), DATABASE.country_code_for(country::JE));
+        assert_eq!(Some(64), DATABASE.country_code_for(country::NZ));
+
+        // Non-geographical entities share the "001" ID, which is not a
+        // country, so their code is only reachable through `by_code`.
+        for code in [800, 808, 870, 979] {
+            for meta in DATABASE.by_code(&code).unwrap() {
+                assert_eq!("001", meta.id());
+                assert!(meta.id().parse::<country::Id>().is_err());
+            }
+        }
+    }
+
+    #[test]
+    fn regions_for() {
+        let nanpa = DATABASE.regions_for(1);
+        assert_eq!(Some(&country::US), nanpa.first());
+        assert!(nanpa.contains(&country::CA));
+        assert!(nanpa.contains(&country::KY));
+        assert_eq!(DATABASE.region(&1).unwrap().len(), nanpa.len());
+
+        assert_eq!(Some(&country::GB), DATABASE.regions_for(44).first());
+        assert_eq!(vec![country::NZ], DATABASE.regions_for(64));
+
+        assert!(DATABASE.regions_for(800).is_empty());
+        assert!(DATABASE.regions_for(999).is_empty());
+    }
+
+    #[test]
+    fn shared_code() {
+        let regions = DATABASE.region(&1).unwrap();
+        let mut unique = regions.clone();
+        unique.sort_unstable();
+        unique.dedup();
+
+        assert_eq!(unique.len(), regions.len());
+        assert_eq!("US", regions[0]);
+        assert_eq!(
+            regions,
+            DATABASE
+                .by_code(&1)
+                .unwrap()
+                .iter()
+                .map(|m| m.id())
+                .collect::<Vec<_>>()
+        );
+        assert_eq!(
+            1,
+            DATABASE
+                .by_code(&1)
+                .unwrap()
+                .iter()
+                .filter(|m| m.is_main_country_for_code())
+                .count()
+        );
+    }
+
+    #[test]
+    fn checked() {
+        let territory = |pattern: &str| {
+            format!(
+                r#"<phoneNumberMetadata><territories><territory id="NZ" countryCode="64"><generalDesc><nationalNumberPattern>{}</nationalNumberPattern></generalDesc></territory></territories></phoneNumberMetadata>"#,
+                pattern
+            )
+        };
+
+        assert!(Database::parse_checked(territory(r"[2-9]\d{7,9}")).is_ok());
+
+        // Valid syntax, but way over the compiled size limit.
+        assert!(Database::parse(territory(r"\w{1000}")).is_ok());
+        assert!(matches!(
+            Database::parse_checked(territory(r"\w{1000}")),
+            Err(error::LoadMetadata::Regex(_))
+        ));
+    }
+
+    #[test]
+    fn metadata() {
+        for id in [country::US, country::GB, country::JE, country::NZ] {
+            let meta = DATABASE.metadata(id).unwrap();
+            assert_eq!(id.as_ref(), meta.id());
+            assert!(std::ptr::eq(meta, DATABASE.by_id(id.as_ref()).unwrap()));
+        }
+
+        let empty = Database::from(Vec::new()).unwrap();
+        assert!(empty.metadata(country::US).is_none());
+    }
+
+    #[test]
+    fn with_overlay() {
+        let overlay = metadata::edited(|metadata| {
+            metadata.retain(|m| {
+                matches!(
+                    (m.id.as_deref(), m.country_code),
+                    (Some("NZ"), Some(64)) | (Some("JE"), Some(44)) | (Some("001"), Some(800))
+                )
+            });
+
+            for meta in metadata {
+                match meta.id.as_deref() {
+                    Some("NZ") => meta.national_prefix = Some("9".into()),
+                    Some("JE") => meta.country_code = Some(999),
+                    _ => meta.national_prefix = Some("8".into()),
+                }
+            }
+        });
+        let database = DATABASE.with_overlay(overlay);
+
+        assert_eq!(Some("9"), database.by_id("NZ").unwrap().national_prefix());
+        assert_eq!(Some("0"), DATABASE.by_id("NZ").unwrap().national_prefix());
+        assert_eq!(1, database.by_code(&64).unwrap().len());
+        assert_eq!(
+            database
+                .by_id("US")
+                .unwrap()
+                .descriptors()
+                .general()
+                .national_number()
+                .as_str(),
+            DATABASE
+                .by_id("US")
+                .unwrap()
+                .descriptors()
+                .general()
+                .national_number()
+                .as_str()
+        );
+
+        // Regions moved to another code are only listed under the new one.
+        assert_eq!(Some(vec!["JE"]), database.region(&999));
+        assert!(!database.region(&44).unwrap().contains(&"JE"));
+        assert_eq!(
+            DATABASE.region(&44).unwrap()[0],
+            database.region(&44).unwrap()[0]
+        );
+
+        // Non-geographical entities are replaced by code.
+        let by_code = database.by_code(&800).unwrap();
+        assert_eq!(1, by_code.len());
+        assert_eq!(Some("8"), by_code[0].national_prefix());
+        assert_eq!(
+            DATABASE.by_code(&808).unwrap()[0].country_code(),
+            database.by_code(&808).unwrap()[0].country_code()
+        );
+        assert_eq!(DATABASE.iter().count(), database.iter().count());
+    }
+
+    #[test]
+    fn duplicate_regions() {
+        let database = metadata::edited(|metadata| {
+            let duplicates = metadata
+                .iter()
+                .filter(|m| matches!(m.id.as_deref(), Some("US") | Some("CA")))
+                .cloned()
+                .collect::<Vec<_>>();
+
+            metadata.extend(duplicates);
+        });
+        let regions = database.region(&1).unwrap();
+
+        assert_eq!(DATABASE.region(&1).unwrap().len(), regions.len());
+        assert_eq!("US", regions[0]);
+        assert_eq!(1, regions.iter().filter(|&&id| id == "CA").count());
+        assert_eq!(regions.len(), database.by_code(&1).unwrap().len());
+
+        // The non-geographical entities are all kept.
+        assert_eq!(
+            DATABASE.by_code(&800).unwrap().len(),
+            database.by_code(&800).unwrap().len()
+        );
+        assert_eq!(
+            DATABASE.by_code(&808).unwrap().len(),
+            database.by_code(&808).unwrap().len()
+        );
+    }
+
+    #[test]
+    fn all_example_numbers() {
+        let mut count = 0;
+
+        for (id, kind, example) in DATABASE.all_example_numbers() {
+            let code = DATABASE.country_code_for(id).unwrap();
+            let number = parser::parse(None, format!("+{code}{example}"))
+                .unwrap_or_else(|e| panic!("{id:?} {kind:?} example {example} failed: {e}"));
+
+            assert!(
+                validator::is_valid(&number),
+                "{id:?} {kind:?} example {example} is invalid"
+            );
+
+            let found = number.number_type(&DATABASE);
+            assert!(
+                found == kind
+                    || found == Type::FixedLineOrMobile
+                        && matches!(kind, Type::FixedLine | Type::Mobile),
+                "{id:?} {kind:?} example {example} found {found:?}"
+            );
+
+            count += 1;
+        }
+
+        assert!(count > 1000);
+    }
+
+    #[test]
+    fn split_e164() {
+        assert_eq!(Some((1, "6502530000")), DATABASE.split_e164("+16502530000"));
+        assert_eq!(
+            Some((44, "2070313000")),
+            DATABASE.split_e164("+442070313000")
+        );
+        assert_eq!(Some((800, "12345678")), DATABASE.split_e164("+80012345678"));
+        assert_eq!(
+            Some((39, "0612345678")),
+            DATABASE.split_e164("+390612345678")
+        );
+        assert_eq!(Some((64, "")), DATABASE.split_e164("+64"));
+
+        assert_eq!(None, DATABASE.split_e164(""));
+        assert_eq!(None, DATABASE.split_e164("+"));
+        assert_eq!(None, DATABASE.split_e164("442070313000"));
+        assert_eq!(None, DATABASE.split_e164("+0442070313000"));
+        assert_eq!(None, DATABASE.split_e164("+44-20-7031-3000"));
+        assert_eq!(None, DATABASE.split_e164("+2801234"));
+
+        let empty = Database::from(Vec::new()).unwrap();
+        assert_eq!(None, empty.split_e164("+442070313000"));
+    }
+
+    #[test]
+    fn prefixes() {
+        let prefixes = super::prefixes("268").unwrap();
+        assert_eq!(Some(true), prefixes[268]);
+        assert_eq!(1, prefixes.iter().filter(|p| p.is_some()).count());
+
+        let prefixes = super::prefixes("8001 | 8[024]9").unwrap();
+        assert_eq!(Some(false), prefixes[800]);
+        assert_eq!(Some(true), prefixes[809]);
+        assert_eq!(Some(true), prefixes[849]);
+        assert_eq!(None, prefixes[819]);
+
+        let prefixes = super::prefixes("[2-46]").unwrap();
+        assert!((200..500)
+            .chain(600..700)
+            .all(|p| prefixes[p] == Some(true)));
+        assert_eq!(400, prefixes.iter().filter(|p| p.is_some()).count());
+
+        assert!(super::prefixes("").is_none());
+        assert!(super::prefixes("2|").is_none());
+        assert!(super::prefixes("[2-]").is_none());
+        assert!(super::prefixes("[^2]").is_none());
+        assert!(super::prefixes("8(?:00|88)").is_none());
+        assert!(super::prefixes("\\d").is_none());
+    }
+
+    #[test]
+    fn candidates() {
+        let ids = |national| {
+            DATABASE
+                .candidates(1, national)
+                .unwrap()
+                .iter()
+                .map(|(meta, certain)| (meta.id(), *certain))
+                .collect::<Vec<_>>()
+        };
+
+        assert_eq!(vec![("US", false), ("AG", true)], ids("2684601234"));
+        assert_eq!(vec![("US", false), ("CA", false)], ids("6502530000"));
+        assert_eq!(
+            vec![("US", false), ("CA", false), ("DO", true)],
+            ids("8092345678")
+        );
+
+        assert!(DATABASE.candidates(1, "65").is_none());
+        assert!(DATABASE.candidates(64, "33160055").is_none());
+
+        // The index follows the regions moved by an overlay.
+        let database = DATABASE.with_overlay(metadata::edited(|metadata| {
+            metadata.retain(|m| m.id.as_deref() == Some("AG"));
+            metadata[0].country_code = Some(999);
+        }));
+        assert!(database
+            .candidates(1, "2684601234")
+            .unwrap()
+            .iter()
+            .all(|(meta, _)| meta.id() != "AG"));
+    }
 }
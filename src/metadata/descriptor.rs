@@ -77,9 +77,39 @@ impl Descriptor {
             return false;
         }
 
+        self.match_len(value).is_some()
+    }
+
+    /// Get the length of the national number pattern match at the start of the
+    /// given value, if any.
+    ///
+    /// Unlike [`is_match`](Self::is_match) the possible lengths are not
+    /// checked, so this can be used to find how much of a longer string is
+    /// consumed by the pattern.
+    pub fn match_len(&self, value: &str) -> Option<usize> {
         self.national_number
             .find(value)
-            .map(|m| m.start() == 0)
-            .unwrap_or(false)
+            .filter(|m| m.start() == 0)
+            .map(|m| m.end())
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::country;
+    use crate::metadata::DATABASE;
+
+    #[test]
+    fn match_len() {
+        let general = DATABASE
+            .by_id(country::US.as_ref())
+            .unwrap()
+            .descriptors()
+            .general();
+
+        assert_eq!(Some(10), general.match_len("6502530000"));
+        assert_eq!(Some(10), general.match_len("6502530000 and more"));
+        assert_eq!(None, general.match_len("x6502530000"));
+        assert!(general.is_match("6502530000"));
     }
 }
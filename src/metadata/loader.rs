@@ -264,6 +264,14 @@ fn territory<R: BufRead>(
 
                 name @ b"voicemail" => meta.voicemail = Some(descriptor(reader, &meta, name)?),
 
+                name @ b"shortCode" => meta.short_code = Some(descriptor(reader, &meta, name)?),
+
+                name @ b"standardRate" => {
+                    meta.standard_rate = Some(descriptor(reader, &meta, name)?)
+                }
+
+                name @ b"carrierSpecific" => meta.carrier = Some(descriptor(reader, &meta, name)?),
+
                 name @ b"noInternationalDialling" => {
                     meta.no_international = Some(descriptor(reader, &meta, name)?)
                 }
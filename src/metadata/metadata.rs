@@ -66,6 +66,20 @@ impl Metadata {
         &self.descriptors
     }
 
+    /// The possible lengths of national numbers of the given type, empty if
+    /// there are no numbers of that type.
+    ///
+    /// The lengths for [`Type::Unknown`] are those of the general descriptor,
+    /// which are usually not set, see [`PhoneNumber::possible_lengths`] for the
+    /// lengths of any type of number.
+    ///
+    /// [`PhoneNumber::possible_lengths`]: crate::PhoneNumber::possible_lengths
+    pub fn possible_lengths(&self, kind: Type) -> &[u16] {
+        self.descriptors
+            .get(kind)
+            .map_or(&[], |desc| desc.possible_length())
+    }
+
     /// The CLDR 2-letter representation of a country/region, with the exception
     /// of "country calling codes" used for non-geographical entities, such as
     /// Universal International Toll Free Number (+800). These are all given the
@@ -23,7 +23,23 @@ mod metadata;
 pub use self::metadata::{Descriptors, Metadata};
 
 mod database;
-pub use self::database::{Database, DEFAULT as DATABASE};
+pub use self::database::{set_default_database, Database, DEFAULT as DATABASE};
 
 /// XML loading helpers.
 pub mod loader;
+
+/// Build a database out of the bundled metadata after applying the given
+/// edits to it, for tests needing slightly different metadata.
+#[cfg(test)]
+pub(crate) fn edited<F: FnOnce(&mut Vec<loader::Metadata>)>(edit: F) -> Database {
+    use std::fs::File;
+    use std::io::BufReader;
+
+    let mut metadata = loader::load(BufReader::new(
+        File::open("assets/PhoneNumberMetadata.xml").unwrap(),
+    ))
+    .unwrap();
+
+    edit(&mut metadata);
+    Database::from(metadata).unwrap()
+}
@@ -13,9 +13,14 @@
 // limitations under the License.
 
 use serde_derive::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt;
+use std::ops::Deref;
 
 /// The national number part of a phone number.
+///
+/// National numbers are ordered by their value first and then by the number of
+/// leading zeros.
 #[derive(Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Hash, Debug)]
 pub struct NationalNumber {
     pub(crate) value: u64,
@@ -61,6 +66,64 @@ impl NationalNumber {
     pub fn zeros(&self) -> u8 {
         (self.value >> 56) as u8
     }
+
+    /// The decimal digits of the number, including the leading zeros.
+    ///
+    /// This is the same as `to_string`, but formats into a buffer on the
+    /// stack, so that matching a number against the metadata doesn't
+    /// allocate.
+    pub(crate) fn digits(&self) -> Digits {
+        let mut digits = Digits {
+            buffer: [b'0'; Digits::CAPACITY],
+            start: Digits::CAPACITY,
+        };
+
+        let mut value = self.value();
+        loop {
+            digits.start -= 1;
+            digits.buffer[digits.start] = b'0' + (value % 10) as u8;
+            value /= 10;
+
+            if value == 0 {
+                break;
+            }
+        }
+
+        // The buffer is already filled with zeros.
+        digits.start -= self.zeros() as usize;
+        digits
+    }
+}
+
+/// The digits of a national number, see [`NationalNumber::digits`].
+pub(crate) struct Digits {
+    buffer: [u8; Digits::CAPACITY],
+    start: usize,
+}
+
+impl Digits {
+    /// Up to 255 leading zeros and the 17 digits of a 56 bits value.
+    const CAPACITY: usize = 255 + 17;
+}
+
+impl Deref for Digits {
+    type Target = str;
+
+    fn deref(&self) -> &str {
+        std::str::from_utf8(&self.buffer[self.start..]).expect("digits are ASCII")
+    }
+}
+
+impl Ord for NationalNumber {
+    fn cmp(&self, other: &Self) -> Ordering {
+        (self.value(), self.zeros()).cmp(&(other.value(), other.zeros()))
+    }
+}
+
+impl PartialOrd for NationalNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 impl From<NationalNumber> for u64 {
@@ -69,6 +132,13 @@ impl From<NationalNumber> for u64 {
     }
 }
 
+/// The same as converting an owned `NationalNumber`, without moving it.
+impl From<&NationalNumber> for u64 {
+    fn from(n: &NationalNumber) -> u64 {
+        n.value
+    }
+}
+
 impl fmt::Display for NationalNumber {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         for _ in 0..self.zeros() {
@@ -78,3 +148,26 @@ impl fmt::Display for NationalNumber {
         write!(f, "{}", self.value())
     }
 }
+
+#[cfg(test)]
+mod test {
+    use crate::national_number::NationalNumber;
+
+    #[test]
+    fn into_u64() {
+        let number = NationalNumber::new(612345678, 1).unwrap();
+
+        assert_eq!(u64::from(number), u64::from(&number));
+        assert_eq!(u64::from(number), Into::<u64>::into(&number));
+        assert_eq!(612345678, number.value());
+        assert_eq!("0612345678", number.to_string());
+    }
+
+    #[test]
+    fn digits() {
+        for (value, zeros) in [(0, 0), (0, 2), (612345678, 1), ((1 << 56) - 1, 255)] {
+            let number = NationalNumber::new(value, zeros).unwrap();
+            assert_eq!(number.to_string(), &*number.digits());
+        }
+    }
+}
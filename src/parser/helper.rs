@@ -66,6 +66,7 @@ pub struct Number<'a> {
     pub prefix: Option<Cow<'a, str>>,
     pub extension: Option<Cow<'a, str>>,
     pub carrier: Option<Cow<'a, str>>,
+    pub subaddress: Option<Cow<'a, str>>,
 }
 
 pub fn ieof(i: &str) -> IResult<&str, ()> {
@@ -122,6 +123,8 @@ pub fn extract(value: &str) -> IResult<&str, &str> {
         result = &result[..trailing.start()];
     }
 
+    result = strip_trailing_hash(result);
+
     if let Some(extra) = consts::SECOND_NUMBER_START.find(result) {
         result = &result[..extra.start()];
     }
@@ -133,6 +136,72 @@ pub fn extract(value: &str) -> IResult<&str, &str> {
     }
 }
 
+/// Removes a trailing `#` unless it ends an extension, otherwise it is a
+/// dialling artifact marking the end of the number (e.g. `"650 253 0000#"`).
+///
+/// The `#` only ends an extension introduced by a marker, as in
+/// `"650 253 0000 x1234#"`; digits before it without a marker are part of the
+/// number.
+pub fn strip_trailing_hash(value: &str) -> &str {
+    match value.strip_suffix('#') {
+        Some(number) if !consts::EXTN_PATTERN.is_match(number) => consts::UNWANTED_END_CHARS
+            .find(number)
+            .map_or(number, |trailing| &number[..trailing.start()]),
+
+        _ => value,
+    }
+}
+
+/// Removes control characters, like newlines or tabs pasted in the middle of a
+/// number, none of which are meaningful in a phone number or RFC3966 URI.
+///
+/// `None` is returned when there is nothing to remove.
+pub fn strip_control(value: &str) -> Option<String> {
+    if value.contains(char::is_control) {
+        Some(value.chars().filter(|c| !c.is_control()).collect())
+    } else {
+        None
+    }
+}
+
+/// Map an offset into the input stripped by `strip_control` back to an offset
+/// into the original input.
+pub fn unstripped_offset(original: &str, offset: usize) -> usize {
+    let mut kept = 0;
+
+    for (index, c) in original.char_indices() {
+        if kept == offset && !c.is_control() {
+            return index;
+        }
+
+        if !c.is_control() {
+            kept += c.len_utf8();
+        }
+    }
+
+    original.len()
+}
+
+/// Replaces a literal "plus" at the start of the input, as commonly produced
+/// by voice transcription, with a `+` sign.
+///
+/// Only a "plus" directly followed by a digit, optionally separated by
+/// whitespace, is replaced; `None` is returned when there is nothing to
+/// replace.
+pub fn spelled_plus(value: &str) -> Option<String> {
+    let value = value.trim_start();
+    let rest = value
+        .get(..4)
+        .filter(|word| word.eq_ignore_ascii_case("plus"))
+        .map(|_| value[4..].trim_start())?;
+
+    if rest.starts_with(|c: char| c.is_ascii_digit()) {
+        Some(format!("+{rest}"))
+    } else {
+        None
+    }
+}
+
 /// Parse and insert the proper country code.
 pub fn country_code<'a>(
     database: &Database,
@@ -150,12 +219,16 @@ pub fn country_code<'a>(
         // from the number already.
         country::Source::Plus | country::Source::Idd | country::Source::Number => {
             if number.national.len() <= consts::MIN_LENGTH_FOR_NSN {
-                return Err(error::Parse::TooShortNsn);
+                return Err(if number.country == country::Source::Idd {
+                    error::Parse::TooShortAfterIdd
+                } else {
+                    error::Parse::TooShortNsn
+                });
             }
 
             // If the prefix was already extracted, check it is valid.
-            if number.prefix.is_some() {
-                let prefix = number.prefix.as_ref().unwrap().parse()?;
+            if let Some(prefix) = &number.prefix {
+                let prefix = prefix.parse()?;
 
                 if database.by_code(&prefix).is_none() {
                     return Err(error::Parse::InvalidCountryCode);
@@ -169,9 +242,19 @@ pub fn country_code<'a>(
                     return Err(error::Parse::InvalidCountryCode);
                 }
 
-                // Try to find the first available country code.
+                // Try to find the first available country code, the national
+                // number should only contain ASCII digits by now, but don't
+                // rely on it.
                 for len in 1..consts::MAX_LENGTH_FOR_COUNTRY_CODE + 1 {
-                    let code = number.national[..len].parse().unwrap();
+                    let code = match number
+                        .national
+                        .get(..len)
+                        .filter(|code| code.bytes().all(|b| b.is_ascii_digit()))
+                        .and_then(|code| code.parse::<u16>().ok())
+                    {
+                        Some(code) => code,
+                        None => return Err(error::Parse::InvalidCountryCode),
+                    };
 
                     if database.by_code(&code).is_some() {
                         number.national = trim(number.national, len);
@@ -269,7 +352,12 @@ pub fn national_number<'a>(meta: &Metadata, mut number: Number<'a>) -> Number<'a
         re
     } else {
         if let Some(prefix) = meta.national_prefix.as_ref() {
-            if number.national.starts_with(prefix) {
+            let general = &meta.descriptors.general;
+
+            if number.national.starts_with(prefix)
+                && (!general.is_match(&number.national)
+                    || general.is_match(&number.national[prefix.len()..]))
+            {
                 number.national = trim(number.national, prefix.len());
             }
         }
@@ -302,7 +390,7 @@ pub fn national_number<'a>(meta: &Metadata, mut number: Number<'a>) -> Number<'a
         .unwrap();
 
     if transform.is_none() || last.is_none() {
-        if viable && !meta.descriptors.general.is_match(&number.national[start..]) {
+        if viable && !meta.descriptors.general.is_match(&number.national[end..]) {
             return number;
         }
 
@@ -367,6 +455,49 @@ pub fn normalize<'a>(mut number: Number<'a>, mappings: &FnvHashMap<char, char>)
     number
 }
 
+/// Find the boundaries between the groups of digits of the national number in
+/// the number as it was written.
+///
+/// Returns `None` if the national number is not how the written number ends,
+/// e.g. when the national prefix transform rule changed it.
+pub fn groups(raw: &str, national: &str) -> Option<Box<[u8]>> {
+    let mut digits = String::new();
+    let mut boundaries = Vec::new();
+    let mut separated = false;
+
+    for ch in raw.chars() {
+        match ch
+            .as_dec_digit()
+            .or_else(|| consts::ALPHA_PHONE_MAPPINGS.get(&ch).copied())
+        {
+            Some(digit) => {
+                if separated && !digits.is_empty() {
+                    boundaries.push(digits.len());
+                }
+
+                separated = false;
+                digits.push(digit);
+            }
+
+            None => separated = true,
+        }
+    }
+
+    if !digits.ends_with(national) {
+        return None;
+    }
+
+    let start = digits.len() - national.len();
+
+    Some(
+        boundaries
+            .into_iter()
+            .filter(|&boundary| boundary > start)
+            .map(|boundary| (boundary - start) as u8)
+            .collect(),
+    )
+}
+
 pub fn trim(value: Cow<'_, str>, start: usize) -> Cow<'_, str> {
     match value {
         Cow::Borrowed(value) => Cow::Borrowed(&value[start..]),
@@ -464,6 +595,39 @@ mod test {
         assert!(helper::plus("a").is_err());
     }
 
+    #[test]
+    fn spelled_plus() {
+        assert_eq!(
+            Some("+44 20 7031 3000".to_owned()),
+            helper::spelled_plus("plus 44 20 7031 3000")
+        );
+        assert_eq!(
+            Some("+44 20 7031 3000".to_owned()),
+            helper::spelled_plus("  Plus  44 20 7031 3000")
+        );
+        assert_eq!(Some("+44".to_owned()), helper::spelled_plus("PLUS44"));
+        assert_eq!(None, helper::spelled_plus("+44 20 7031 3000"));
+        assert_eq!(None, helper::spelled_plus("plus four four"));
+        assert_eq!(None, helper::spelled_plus("plush 44"));
+        assert_eq!(None, helper::spelled_plus("plus"));
+    }
+
+    #[test]
+    fn strip_control() {
+        assert_eq!(
+            Some("020 70313000".to_owned()),
+            helper::strip_control("020 \n7031\t3000")
+        );
+        assert_eq!(None, helper::strip_control("020 7031 3000"));
+
+        // Offsets after removed characters are moved past them.
+        let original = "\n02\t0\u{85}7";
+        assert_eq!(1, helper::unstripped_offset(original, 0));
+        assert_eq!(4, helper::unstripped_offset(original, 2));
+        assert_eq!(7, helper::unstripped_offset(original, 3));
+        assert_eq!(original.len(), helper::unstripped_offset(original, 4));
+    }
+
     #[test]
     fn extract() {
         // Removes preceding funky punctuation and letters but leaves the rest untouched.
@@ -512,6 +676,24 @@ mod test {
             "650) 253-0000",
             helper::extract("(650) 253-0000\u{200F}").unwrap().1
         );
+
+        // A trailing hash is removed unless it ends an extension.
+        assert_eq!(
+            "650) 253-0000",
+            helper::extract("(650) 253-0000#").unwrap().1
+        );
+        assert_eq!(
+            "650) 253-0000",
+            helper::extract("(650) 253-0000 #").unwrap().1
+        );
+        assert_eq!(
+            "650) 253-0000 1234",
+            helper::extract("(650) 253-0000 1234#").unwrap().1
+        );
+        assert_eq!(
+            "650) 253-0000 x1234#",
+            helper::extract("(650) 253-0000 x1234#").unwrap().1
+        );
     }
 
     #[test]
@@ -700,7 +882,7 @@ mod test {
                 ..Default::default()
             },
             helper::international_prefix(
-                Some(&CachedRegex::new(DATABASE.cache(), "00[39]").unwrap()),
+                Some(&CachedRegex::new(DATABASE.caches()[0].clone(), "00[39]").unwrap()),
                 Number {
                     national: "0034567700-3898003".into(),
 
@@ -717,7 +899,7 @@ mod test {
                 ..Default::default()
             },
             helper::international_prefix(
-                Some(&CachedRegex::new(DATABASE.cache(), "00[39]").unwrap()),
+                Some(&CachedRegex::new(DATABASE.caches()[0].clone(), "00[39]").unwrap()),
                 Number {
                     national: "00945677003898003".into(),
 
@@ -734,7 +916,7 @@ mod test {
                 ..Default::default()
             },
             helper::international_prefix(
-                Some(&CachedRegex::new(DATABASE.cache(), "00[39]").unwrap()),
+                Some(&CachedRegex::new(DATABASE.caches()[0].clone(), "00[39]").unwrap()),
                 Number {
                     national: "00 9 45677003898003".into(),
 
@@ -750,7 +932,7 @@ mod test {
                 ..Default::default()
             },
             helper::international_prefix(
-                Some(&CachedRegex::new(DATABASE.cache(), "00[39]").unwrap()),
+                Some(&CachedRegex::new(DATABASE.caches()[0].clone(), "00[39]").unwrap()),
                 Number {
                     national: "45677003898003".into(),
 
@@ -767,7 +949,7 @@ mod test {
                 ..Default::default()
             },
             helper::international_prefix(
-                Some(&CachedRegex::new(DATABASE.cache(), "00[39]").unwrap()),
+                Some(&CachedRegex::new(DATABASE.caches()[0].clone(), "00[39]").unwrap()),
                 Number {
                     national: "+45677003898003".into(),
 
@@ -20,8 +20,10 @@ use crate::extension::Extension;
 use crate::metadata::{Database, DATABASE};
 use crate::national_number::NationalNumber;
 use crate::phone_number::{PhoneNumber, Type};
-use crate::validator::{self, Validation};
-use nom::{branch::alt, IResult};
+use crate::validator::{self, number_type, Validation};
+use either::Left;
+use nom::{branch::alt, error::ErrorKind, IResult};
+use std::borrow::Cow;
 
 #[macro_use]
 pub mod helper;
@@ -43,23 +45,575 @@ pub fn parse_with<S: AsRef<str>>(
     country: Option<country::Id>,
     string: S,
 ) -> Result<PhoneNumber, error::Parse> {
+    parse_with_options(database, country, string, ParseOptions::default())
+}
+
+/// Options changing what is kept when parsing a phone number, see
+/// [`parse_with_options`].
+#[derive(Copy, Clone, Eq, PartialEq, Default, Debug)]
+pub struct ParseOptions {
+    keep_groups: bool,
+}
+
+impl ParseOptions {
+    /// Create the default options, the same used by [`parse`].
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Keep the boundaries between the groups of digits of the national
+    /// number as they were written, see [`PhoneNumber::original_groups`].
+    ///
+    /// This costs an allocation of one byte per group boundary for every parsed
+    /// number.
+    pub fn keep_groups(mut self, value: bool) -> Self {
+        self.keep_groups = value;
+        self
+    }
+}
+
+/// Parse a phone number using a specific `Database` and the given options.
+///
+/// # Example
+///
+/// ```
+/// use phonenumber::{metadata::DATABASE, ParseOptions};
+///
+/// let number = phonenumber::parse_with_options(
+///     &DATABASE,
+///     None,
+///     "+44 20 7031 3000",
+///     ParseOptions::new().keep_groups(true),
+/// )
+/// .unwrap();
+///
+/// assert_eq!(Some(&[2, 6][..]), number.original_groups());
+/// ```
+pub fn parse_with_options<S: AsRef<str>>(
+    database: &Database,
+    country: Option<country::Id>,
+    string: S,
+    options: ParseOptions,
+) -> Result<PhoneNumber, error::Parse> {
+    sanitized(string.as_ref(), |string| {
+        // Try to parse the number as RFC3966 or natural language.
+        let (_, number) = phone_number(string)?;
+        let raw = number.national.clone();
+
+        Ok(resolve(database, country, number).map(|mut number| {
+            if options.keep_groups {
+                number.groups = helper::groups(&raw, &number.national.digits());
+            }
+
+            number
+        }))
+    })?
+}
+
+/// Parse a phone number, only returning it if it is also valid.
+///
+/// # Example
+///
+/// ```
+/// use phonenumber::{country, ParseOrInvalid};
+///
+/// assert!(phonenumber::parse_valid(Some(country::GB), "020 7031 3000").is_ok());
+/// assert!(matches!(
+///     phonenumber::parse_valid(Some(country::GB), "07912 34567"),
+///     Err(ParseOrInvalid::Invalid)
+/// ));
+/// assert!(matches!(
+///     phonenumber::parse_valid(None, "020 7031 3000"),
+///     Err(ParseOrInvalid::Parse(_))
+/// ));
+/// ```
+pub fn parse_valid<S: AsRef<str>>(
+    country: Option<country::Id>,
+    string: S,
+) -> Result<PhoneNumber, error::ParseOrInvalid> {
+    parse_valid_with(&DATABASE, country, string)
+}
+
+/// Parse a phone number using a specific `Database`, only returning it if it
+/// is also valid.
+pub fn parse_valid_with<S: AsRef<str>>(
+    database: &Database,
+    country: Option<country::Id>,
+    string: S,
+) -> Result<PhoneNumber, error::ParseOrInvalid> {
+    let number = parse_with(database, country, string)?;
+
+    if validator::is_valid_with(database, &number) {
+        Ok(number)
+    } else {
+        Err(error::ParseOrInvalid::Invalid)
+    }
+}
+
+/// Parse a phone number trying each of the candidate countries as default.
+///
+/// The first candidate yielding a valid number wins, otherwise the number
+/// parsed with the first candidate that accepts it is returned even if it is
+/// not valid. Without candidates this is the same as `parse(None, string)`.
+///
+/// # Example
+///
+/// ```
+/// use phonenumber::country;
+///
+/// let number =
+///     phonenumber::parse_with_regions(&[country::GB, country::DE], "030 1234567").unwrap();
+///
+/// assert_eq!(49, number.code().value());
+/// ```
+pub fn parse_with_regions<S: AsRef<str>>(
+    candidates: &[country::Id],
+    string: S,
+) -> Result<PhoneNumber, error::Parse> {
+    parse_with_regions_with(&DATABASE, candidates, string)
+}
+
+/// Parse a phone number trying each of the candidate countries as default,
+/// using a specific `Database`.
+pub fn parse_with_regions_with<S: AsRef<str>>(
+    database: &Database,
+    candidates: &[country::Id],
+    string: S,
+) -> Result<PhoneNumber, error::Parse> {
+    sanitized(string.as_ref(), |string| {
+        // The input is only normalized once, and resolved for every candidate.
+        let (_, number) = phone_number(string)?;
+
+        let mut parsed = None;
+        let mut error = None;
+
+        for &country in candidates {
+            match resolve(database, Some(country), number.clone()) {
+                Ok(number) if validator::is_valid_with(database, &number) => {
+                    return Ok(Ok(number));
+                }
+
+                Ok(number) => {
+                    parsed.get_or_insert(number);
+                }
+
+                Err(e) => {
+                    error.get_or_insert(e);
+                }
+            }
+        }
+
+        Ok(match (parsed, error) {
+            (Some(number), _) => Ok(number),
+            (None, Some(e)) => Err(e),
+            (None, None) => resolve(database, None, number),
+        })
+    })?
+}
+
+/// Guess the most likely country of a phone number among the candidates.
+///
+/// Candidates the number is valid for are preferred over the ones where it
+/// only matches some type of number, which are preferred over the ones where
+/// it only has a possible length, in case of a tie the first candidate wins.
+/// Candidates where the number doesn't fit at all are never returned.
+///
+/// # Example
+///
+/// ```
+/// use phonenumber::country;
+///
+/// assert_eq!(
+///     Some(country::US),
+///     phonenumber::guess_region("650 253 0000", &[country::GB, country::US]),
+/// );
+/// ```
+pub fn guess_region<S: AsRef<str>>(string: S, among: &[country::Id]) -> Option<country::Id> {
+    guess_region_with(&DATABASE, string, among)
+}
+
+/// Guess the most likely country of a phone number among the candidates using
+/// a specific `Database`.
+pub fn guess_region_with<S: AsRef<str>>(
+    database: &Database,
+    string: S,
+    among: &[country::Id],
+) -> Option<country::Id> {
+    sanitized(string.as_ref(), |string| {
+        let (_, number) = phone_number(string)?;
+
+        let mut best = None;
+
+        for &country in among {
+            let meta = if let Some(meta) = database.metadata(country) {
+                meta
+            } else {
+                continue;
+            };
+
+            let code = meta.country_code();
+            let national = match resolve(database, Some(country), number.clone()) {
+                Ok(number) if number.code().value() == code => number.national.digits(),
+                _ => continue,
+            };
+
+            let score = if number_type(meta, &national) == Type::Unknown {
+                if validator::any_length(meta, national.len() as u16).is_possible() {
+                    1
+                } else {
+                    continue;
+                }
+            } else if validator::source_for(database, code, &national) == Some(Left(country)) {
+                3
+            } else {
+                2
+            };
+
+            if best.map_or(true, |(best, _)| score > best) {
+                best = Some((score, country));
+            }
+        }
+
+        Ok(best.map(|(_, country)| country))
+    })
+    .ok()
+    .flatten()
+}
+
+/// Parse a batch of phone numbers.
+///
+/// The results are in the same order as the input.
+///
+/// # Example
+///
+/// ```
+/// use phonenumber::country;
+///
+/// let numbers = phonenumber::parse_all(Some(country::GB), ["020 7031 3000", "nope"]);
+///
+/// assert!(numbers[0].is_ok());
+/// assert!(numbers[1].is_err());
+/// ```
+pub fn parse_all<I, S>(
+    country: Option<country::Id>,
+    iter: I,
+) -> Vec<Result<PhoneNumber, error::Parse>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    parse_all_with(&DATABASE, country, iter)
+}
+
+/// Parse a batch of phone numbers using a specific `Database`.
+pub fn parse_all_with<I, S>(
+    database: &Database,
+    country: Option<country::Id>,
+    iter: I,
+) -> Vec<Result<PhoneNumber, error::Parse>>
+where
+    I: IntoIterator<Item = S>,
+    S: AsRef<str>,
+{
+    iter.into_iter()
+        .map(|string| parse_with(database, country, string))
+        .collect()
+}
+
+/// Parse a batch of phone numbers in parallel.
+///
+/// The results are in the same order as the input.
+#[cfg(feature = "rayon")]
+pub fn par_parse_all<I, S>(
+    country: Option<country::Id>,
+    iter: I,
+) -> Vec<Result<PhoneNumber, error::Parse>>
+where
+    I: rayon::iter::IntoParallelIterator<Item = S>,
+    I::Iter: rayon::iter::IndexedParallelIterator,
+    S: AsRef<str>,
+{
+    par_parse_all_with(&DATABASE, country, iter)
+}
+
+/// Parse a batch of phone numbers in parallel using a specific `Database`.
+#[cfg(feature = "rayon")]
+pub fn par_parse_all_with<I, S>(
+    database: &Database,
+    country: Option<country::Id>,
+    iter: I,
+) -> Vec<Result<PhoneNumber, error::Parse>>
+where
+    I: rayon::iter::IntoParallelIterator<Item = S>,
+    I::Iter: rayon::iter::IndexedParallelIterator,
+    S: AsRef<str>,
+{
+    use rayon::iter::ParallelIterator;
+
+    iter.into_par_iter()
+        .map(|string| parse_with(database, country, string))
+        .collect()
+}
+
+/// Parse a phone number, only accepting numbers in international format with a
+/// leading plus (e.g. `"+44 20 7031 3000"` or `"tel:+44-20-7031-3000"`).
+///
+/// Numbers relying on a default country or an international dialling prefix
+/// are rejected with [`ParseError::MissingPlus`](crate::ParseError::MissingPlus).
+///
+/// # Example
+///
+/// ```
+/// use phonenumber::ParseError;
+///
+/// assert!(phonenumber::parse_strict("+44 20 7031 3000").is_ok());
+/// assert!(matches!(
+///     phonenumber::parse_strict("020 7031 3000"),
+///     Err(ParseError::MissingPlus)
+/// ));
+/// ```
+pub fn parse_strict<S: AsRef<str>>(string: S) -> Result<PhoneNumber, error::Parse> {
+    parse_strict_with(&DATABASE, string)
+}
+
+/// Parse a phone number using a specific `Database`, only accepting numbers in
+/// international format with a leading plus.
+pub fn parse_strict_with<S: AsRef<str>>(
+    database: &Database,
+    string: S,
+) -> Result<PhoneNumber, error::Parse> {
+    sanitized(string.as_ref(), |string| {
+        let (_, number) = phone_number(string)?;
+
+        if helper::international_prefix(None, number.clone()).country != country::Source::Plus {
+            return Ok(Err(error::Parse::MissingPlus));
+        }
+
+        Ok(resolve(database, None, number))
+    })?
+}
+
+/// Parse a phone number in E.164 format, a plus followed by the country code
+/// and the national number without any separators (e.g. `"+442070313000"`).
+///
+/// This skips detecting the format of the number and handling punctuation,
+/// which makes it faster than [`parse`] for numbers known to be in E.164
+/// format; anything but digits after the plus, apart from a `#` ending the
+/// number, is [`ParseError::Malformed`](crate::ParseError::Malformed).
+///
+/// # Example
+///
+/// ```
+/// use phonenumber::ParseError;
+///
+/// let number = phonenumber::parse_e164("+442070313000").unwrap();
+/// assert_eq!(44, number.code().value());
+///
+/// assert!(matches!(
+///     phonenumber::parse_e164("+44 20 7031 3000"),
+///     Err(ParseError::Malformed { offset: 3 })
+/// ));
+/// ```
+pub fn parse_e164<S: AsRef<str>>(string: S) -> Result<PhoneNumber, error::Parse> {
+    parse_e164_with(&DATABASE, string)
+}
+
+/// Parse a phone number in E.164 format using a specific `Database`.
+pub fn parse_e164_with<S: AsRef<str>>(
+    database: &Database,
+    string: S,
+) -> Result<PhoneNumber, error::Parse> {
+    let string = helper::strip_trailing_hash(string.as_ref());
+
+    let digits = match string.strip_prefix('+') {
+        Some(digits) => digits,
+        None if string.is_empty() => return Err(error::Parse::EmptyInput),
+        None => return Err(error::Parse::MissingPlus),
+    };
+
+    if let Some(offset) = digits.bytes().position(|b| !b.is_ascii_digit()) {
+        return Err(error::Parse::Malformed { offset: offset + 1 });
+    }
+
+    if digits.is_empty() {
+        return Err(error::Parse::NoNumber);
+    }
+
+    resolve(
+        database,
+        None,
+        helper::Number {
+            national: Cow::Borrowed(string),
+            ..Default::default()
+        },
+    )
+}
+
+/// Run the given parser on the input after removing any control characters
+/// and replacing a spelled out plus.
+///
+/// Inputs without anything but whitespace are `EmptyInput`, and malformed
+/// offsets are reported into the original input.
+fn sanitized<T>(
+    original: &str,
+    parser: impl FnOnce(&str) -> Result<T, nom::Err<nom::error::Error<&str>>>,
+) -> Result<T, error::Parse> {
+    let stripped = helper::strip_control(original);
+    let cleaned = stripped.as_deref().unwrap_or(original);
+
+    if cleaned.trim().is_empty() {
+        return Err(error::Parse::EmptyInput);
+    }
+
+    let spelled = helper::spelled_plus(cleaned);
+    let string = spelled.as_deref().unwrap_or(cleaned);
+
+    parser(string).map_err(|e| match failure(cleaned, string, e) {
+        error::Parse::Malformed { offset } if stripped.is_some() => error::Parse::Malformed {
+            offset: helper::unstripped_offset(original, offset),
+        },
+
+        e => e,
+    })
+}
+
+/// Parse a phone number using a specific `Database`, only accepting RFC3966
+/// URIs (e.g. `"tel:+44-20-7031-3000;ext=123"`).
+pub(crate) fn parse_rfc3966_with<S: AsRef<str>>(
+    database: &Database,
+    string: S,
+) -> Result<PhoneNumber, error::Parse> {
+    sanitized(string.as_ref(), |string| {
+        let (_, number) = rfc3966::phone_number(helper::strip_trailing_hash(string))?;
+
+        Ok(resolve(database, None, number))
+    })?
+}
+
+/// Parse a phone number as RFC3966 or natural language.
+fn phone_number(i: &str) -> IResult<&str, helper::Number<'_>> {
+    parse! { i => alt((rfc3966::phone_number, natural::phone_number)) }
+}
+
+/// Parse a phone number at the start of the given string, returning it
+/// together with the rest of the string following it.
+///
+/// Unlike [`parse`], alpha numbers (e.g. `"0800 FOR PIZZA"`) are not supported,
+/// since letters are considered to be the start of the following text.
+///
+/// # Example
+///
+/// ```
+/// use phonenumber::country;
+///
+/// let (number, rest) =
+///     phonenumber::parse_prefix(Some(country::GB), "020 7031 3000 is the office").unwrap();
+///
+/// assert_eq!(44, number.code().value());
+/// assert_eq!(" is the office", rest);
+/// ```
+pub fn parse_prefix(
+    country: Option<country::Id>,
+    string: &str,
+) -> Result<(PhoneNumber, &str), error::Parse> {
+    parse_prefix_with(&DATABASE, country, string)
+}
+
+/// Parse a phone number at the start of the given string using a specific
+/// `Database`, returning it together with the rest of the string following
+/// it.
+pub fn parse_prefix_with<'a>(
+    database: &Database,
+    country: Option<country::Id>,
+    string: &'a str,
+) -> Result<(PhoneNumber, &'a str), error::Parse> {
     fn phone_number(i: &str) -> IResult<&str, helper::Number<'_>> {
-        parse! { i => alt((rfc3966::phone_number, natural::phone_number)) }
+        parse! { i => alt((rfc3966::phone_number, natural::phone_number_prefix)) }
+    }
+
+    if string.chars().all(|c| c.is_whitespace() || c.is_control()) {
+        return Err(error::Parse::EmptyInput);
     }
 
-    // Try to parse the number as RFC3966 or natural language.
-    let (_, mut number) = phone_number(string.as_ref()).or(Err(error::Parse::NoNumber))?;
+    let spelled = helper::spelled_plus(string);
+    let parsed = spelled.as_deref().unwrap_or(string);
+    let (rest, number) = phone_number(parsed).map_err(|e| failure(string, parsed, e))?;
+
+    // Only the start of the string is changed when replacing a spelled out
+    // plus, so the rest can be taken from the original string.
+    let rest = &string[string.len() - rest.len()..];
+
+    Ok((resolve(database, country, number)?, rest))
+}
+
+/// Convert a parser error into a `Parse` error.
+///
+/// Failures found inside a recognized number are reported with their offset
+/// into the original input, any other error means there is no number at all.
+fn failure(original: &str, parsed: &str, err: nom::Err<nom::error::Error<&str>>) -> error::Parse {
+    match err {
+        // A RFC3966 URI without a national number, see `rfc3966::national`.
+        nom::Err::Failure(e) if e.code == ErrorKind::TakeWhile1 => error::Parse::NoNumber,
+
+        // A RFC3966 extension with invalid characters or too many digits.
+        nom::Err::Failure(e) if e.code == ErrorKind::Digit => error::Parse::InvalidExtension,
+
+        nom::Err::Failure(e) => (e.input.as_ptr() as usize)
+            .checked_sub(parsed.as_ptr() as usize)
+            .filter(|&offset| offset <= parsed.len())
+            // Replacing a spelled out plus only changes the start of the input.
+            .map(|offset| error::Parse::Malformed {
+                offset: (offset + original.len()).saturating_sub(parsed.len()),
+            })
+            .unwrap_or(error::Parse::NoNumber),
+
+        _ => error::Parse::NoNumber,
+    }
+}
 
+/// Build a `PhoneNumber` out of a parsed number.
+fn resolve(
+    database: &Database,
+    country: Option<country::Id>,
+    mut number: helper::Number<'_>,
+) -> Result<PhoneNumber, error::Parse> {
     // Normalize the number and extract country code.
     number = helper::country_code(database, country, number)?;
 
+    if number
+        .extension
+        .as_ref()
+        .map_or(false, |e| e.len() > consts::MAX_LENGTH_FOR_EXTENSION)
+    {
+        return Err(error::Parse::InvalidExtension);
+    }
+
+    // Use the metadata of the given country if it has the extracted country
+    // code, otherwise the main country of the extracted country code, so a
+    // national prefix after a country code (e.g. `+44 (0)20`) is stripped.
+    let code = number
+        .prefix
+        .as_ref()
+        .map(|p| p.parse::<u16>())
+        .transpose()?;
+    let meta = country
+        .and_then(|c| database.by_id(c.as_ref()))
+        .filter(|m| Some(m.country_code) == code)
+        .or_else(|| code.and_then(|code| database.main_metadata(code)));
+
     // Extract carrier and strip national prefix if present.
-    if let Some(meta) = country.and_then(|c| database.by_id(c.as_ref())) {
+    if let Some(meta) = meta {
         let mut potential = helper::national_number(meta, number.clone());
 
-        // Strip national prefix if present.
+        // Strip national prefix if present, unless that would turn a number
+        // matching the general pattern into one that does not.
         if let Some(prefix) = meta.national_prefix.as_ref() {
-            if potential.national.starts_with(prefix) {
+            let general = meta.descriptors().general();
+
+            if potential.national.starts_with(prefix)
+                && (!general.is_match(&potential.national)
+                    || general.is_match(&potential.national[prefix.len()..]))
+            {
                 potential.national = helper::trim(potential.national, prefix.len());
             }
         }
@@ -90,15 +644,19 @@ pub fn parse_with<S: AsRef<str>>(
 
         extension: number.extension.map(|s| Extension(s.into_owned())),
         carrier: number.carrier.map(|s| Carrier(s.into_owned())),
+        subaddress: number.subaddress.map(Cow::into_owned),
+        groups: None,
     })
 }
 
 #[cfg(test)]
 mod test {
     use crate::country;
+    use crate::error;
+    use crate::metadata::DATABASE;
     use crate::national_number::NationalNumber;
-    use crate::parser;
-    use crate::phone_number::PhoneNumber;
+    use crate::parser::{self, ParseOptions};
+    use crate::phone_number::{PhoneNumber, Type};
 
     #[test]
     fn parse() {
@@ -112,6 +670,8 @@ mod test {
 
             extension: None,
             carrier: None,
+            subaddress: None,
+            groups: None,
         };
 
         number.code.source = country::Source::Default;
@@ -131,21 +691,41 @@ mod test {
             number,
             parser::parse(Some(country::NZ), "03 331 6005").unwrap()
         );
+        assert_eq!(
+            number,
+            parser::parse(
+                Some(country::NZ),
+                "tel:03-331-6005;phone-context=example.com"
+            )
+            .unwrap()
+        );
+        assert!(matches!(
+            parser::parse(
+                Some(country::NZ),
+                "tel:03-331-6005;phone-context=example..com"
+            ),
+            Err(error::Parse::Malformed { offset: 30 })
+        ));
 
         number.code.source = country::Source::Plus;
         assert_eq!(
             number,
             parser::parse(Some(country::NZ), "tel:03-331-6005;phone-context=+64").unwrap()
         );
-        // FIXME: What the fuck is this.
-        // assert_eq!(number, parser::parse(Some(country::NZ), "tel:331-6005;phone-context=+64-3").unwrap());
-        // assert_eq!(number, parser::parse(Some(country::NZ), "tel:331-6005;phone-context=+64-3").unwrap());
         assert_eq!(
             number,
-            parser::parse(Some(country::NZ), "tel:03-331-6005;phone-context=+64;a=%A1").unwrap()
+            parser::parse(Some(country::NZ), "tel:331-6005;phone-context=+64-3").unwrap()
         );
         assert_eq!(
             number,
+            parser::parse(Some(country::US), "tel:331-6005;phone-context=+64-3").unwrap()
+        );
+        assert_eq!(
+            number,
+            parser::parse(Some(country::NZ), "tel:03-331-6005;phone-context=+64;a=%A1").unwrap()
+        );
+        assert_eq!(
+            number.clone().with_subaddress("12345"),
             parser::parse(
                 Some(country::NZ),
                 "tel:03-331-6005;isub=12345;phone-context=+64"
@@ -153,7 +733,7 @@ mod test {
             .unwrap()
         );
         assert_eq!(
-            number,
+            number.clone().with_subaddress("12345"),
             parser::parse(Some(country::NZ), "tel:+64-3-331-6005;isub=12345").unwrap()
         );
         assert_eq!(
@@ -200,6 +780,8 @@ mod test {
 
             extension: None,
             carrier: None,
+            subaddress: None,
+            groups: None,
         };
 
         assert_eq!(
@@ -218,6 +800,8 @@ mod test {
 
                 extension: None,
                 carrier: None,
+                subaddress: None,
+                groups: None,
             },
             parser::parse(Some(country::DE), "301/23456").unwrap()
         );
@@ -233,6 +817,8 @@ mod test {
 
                 extension: None,
                 carrier: None,
+                subaddress: None,
+                groups: None,
             },
             parser::parse(Some(country::JP), "+81 *2345").unwrap()
         );
@@ -248,6 +834,8 @@ mod test {
 
                 extension: None,
                 carrier: None,
+                subaddress: None,
+                groups: None,
             },
             parser::parse(Some(country::NZ), "12").unwrap()
         );
@@ -263,11 +851,779 @@ mod test {
 
                 extension: None,
                 carrier: Some("12".into()),
+                subaddress: None,
+                groups: None,
             },
             parser::parse(Some(country::BR), "012 3121286979").unwrap()
         );
     }
 
+    #[test]
+    fn trailing_hash() {
+        let number = parser::parse(Some(country::US), "+1 650 253 0000#").unwrap();
+        assert_eq!(6502530000, number.national().value());
+        assert_eq!(None, number.extension());
+
+        let number = parser::parse(Some(country::US), "+1 650 253 0000 #").unwrap();
+        assert_eq!(6502530000, number.national().value());
+        assert_eq!(None, number.extension());
+
+        let number = parser::parse(Some(country::US), "+1 650 253 0000 x1234#").unwrap();
+        assert_eq!(6502530000, number.national().value());
+        assert_eq!(Some("1234"), number.extension().map(|e| &**e));
+
+        // Without an extension marker, the digits before the hash are part of
+        // the number.
+        let number = parser::parse(None, "+1 650 253 0000 1234#").unwrap();
+        assert_eq!(65025300001234, number.national().value());
+        assert_eq!(None, number.extension());
+
+        // The same holds for every parser.
+        let number = parser::parse_strict("+1 650 253 0000#").unwrap();
+        assert_eq!(6502530000, number.national().value());
+
+        let number = parser::parse_e164("+16502530000#").unwrap();
+        assert_eq!(6502530000, number.national().value());
+
+        let number = parser::parse_rfc3966_with(&DATABASE, "tel:+1-650-253-0000#").unwrap();
+        assert_eq!(6502530000, number.national().value());
+        assert_eq!(None, number.extension());
+
+        let (number, rest) = parser::parse_prefix(None, "+1 650 253 0000# to call").unwrap();
+        assert_eq!(6502530000, number.national().value());
+        assert_eq!(" to call", rest);
+    }
+
+    #[test]
+    fn rfc3966_no_number() {
+        for input in [
+            "tel:;phone-context=+1",
+            ";phone-context=+64",
+            "tel:;ext=1234",
+        ] {
+            let res = parser::parse(None, input);
+            assert!(
+                matches!(res, Err(error::Parse::NoNumber)),
+                "{input}: {res:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn trunk_prefix_after_country_code() {
+        let number = |value: u16, source: country::Source, national: u64| PhoneNumber {
+            code: country::Code { value, source },
+            national: NationalNumber::new(national, 0).unwrap(),
+            extension: None,
+            carrier: None,
+            subaddress: None,
+            groups: None,
+        };
+
+        assert_eq!(
+            number(64, country::Source::Number, 64123456),
+            parser::parse(Some(country::NZ), "64(0)64123456").unwrap()
+        );
+        assert_eq!(
+            number(49, country::Source::Number, 30123456),
+            parser::parse(Some(country::DE), "49(0)30123456").unwrap()
+        );
+        assert_eq!(
+            number(49, country::Source::Number, 30123456),
+            parser::parse(Some(country::DE), "49 (0) 30 123456").unwrap()
+        );
+        assert_eq!(
+            number(44, country::Source::Number, 2070313000),
+            parser::parse(Some(country::GB), "44 (0)20 7031 3000").unwrap()
+        );
+        assert_eq!(
+            number(41, country::Source::Number, 223456789),
+            parser::parse(Some(country::CH), "41(0)223456789").unwrap()
+        );
+
+        // The trunk prefix is stripped according to the country of the
+        // extracted country code, not the given one.
+        assert_eq!(
+            number(49, country::Source::Plus, 30123456),
+            parser::parse(None, "+49(0)30123456").unwrap()
+        );
+        assert_eq!(
+            number(44, country::Source::Plus, 2070313000),
+            parser::parse(None, "+44 (0) 20 7031 3000").unwrap()
+        );
+        assert_eq!(
+            number(44, country::Source::Plus, 2070313000),
+            parser::parse(Some(country::US), "+44(0)2070313000").unwrap()
+        );
+
+        // Also without parentheses, as in numbers copied in mixed
+        // international and national form.
+        assert_eq!(
+            number(49, country::Source::Plus, 17612345678),
+            parser::parse(None, "+49 0176 12345678").unwrap()
+        );
+        assert_eq!(
+            number(49, country::Source::Plus, 17612345678),
+            parser::parse(None, "tel:+49-0176-12345678").unwrap()
+        );
+        assert_eq!(
+            number(49, country::Source::Idd, 17612345678),
+            parser::parse(Some(country::US), "011 49 0176 12345678").unwrap()
+        );
+        assert_eq!(
+            number(33, country::Source::Plus, 142685300),
+            parser::parse(None, "+33 01 42 68 53 00").unwrap()
+        );
+        assert_eq!(
+            number(44, country::Source::Plus, 2070313000),
+            parser::parse(None, "+44 020 7031 3000").unwrap()
+        );
+
+        // Numbers starting with the trunk prefix keep it if stripping it makes
+        // them invalid.
+        assert_eq!(
+            number(7, country::Source::Plus, 8001234567),
+            parser::parse(None, "+7 800 123 45 67").unwrap()
+        );
+        assert_eq!(
+            number(7, country::Source::Default, 8001234567),
+            parser::parse(Some(country::RU), "8 800 123 45 67").unwrap()
+        );
+
+        // Italian numbers keep their leading zero, there is no trunk prefix.
+        let italian = parser::parse(Some(country::IT), "39(0)612345678").unwrap();
+        assert_eq!(39, italian.code().value());
+        assert_eq!("0612345678", italian.national().to_string());
+    }
+
+    #[test]
+    fn parse_prefix() {
+        let (number, rest) =
+            parser::parse_prefix(Some(country::GB), "020 7031 3000 is the office").unwrap();
+        assert_eq!(
+            parser::parse(Some(country::GB), "020 7031 3000").unwrap(),
+            number
+        );
+        assert_eq!(" is the office", rest);
+
+        let (number, rest) =
+            parser::parse_prefix(None, "+1 650 253 0000 ext. 1234, ask for Bob").unwrap();
+        assert_eq!(6502530000, number.national().value());
+        assert_eq!(Some("1234"), number.extension().map(|e| &**e));
+        assert_eq!(", ask for Bob", rest);
+
+        let (number, rest) =
+            parser::parse_prefix(None, "tel:+1-650-253-0000;ext=1234 and more").unwrap();
+        assert_eq!(6502530000, number.national().value());
+        assert_eq!(Some("1234"), number.extension().map(|e| &**e));
+        assert_eq!(" and more", rest);
+
+        let (number, rest) = parser::parse_prefix(None, "+64 3 331 6005").unwrap();
+        assert_eq!(33316005, number.national().value());
+        assert_eq!("", rest);
+
+        assert!(parser::parse_prefix(None, "call me maybe").is_err());
+
+        let (number, rest) = parser::parse_prefix(None, "plus 44 20 7031 3000 please").unwrap();
+        assert_eq!(2070313000, number.national().value());
+        assert_eq!(" please", rest);
+    }
+
+    #[test]
+    fn leading_zero_country_code() {
+        for number in [
+            "+033316005",
+            "+0 3 331 6005",
+            "tel:+033316005",
+            "tel:+0-3-331-6005",
+            "tel:253-0000;phone-context=+0-3",
+        ] {
+            for country in [None, Some(country::NZ)] {
+                assert!(
+                    matches!(
+                        parser::parse(country, number),
+                        Err(error::Parse::InvalidCountryCode)
+                    ),
+                    "{number} with {country:?}"
+                );
+            }
+        }
+    }
+
+    #[test]
+    fn malformed_offset() {
+        fn failure(input: &str, offset: usize) -> nom::Err<nom::error::Error<&str>> {
+            nom::Err::Failure(nom::error::Error::new(
+                &input[offset..],
+                nom::error::ErrorKind::Verify,
+            ))
+        }
+
+        let input = "650 253 0000;ext=";
+        assert!(matches!(
+            parser::failure(input, input, failure(input, 12)),
+            error::Parse::Malformed { offset: 12 }
+        ));
+
+        // Offsets into a number with a spelled out plus point into the
+        // original input.
+        let (original, parsed) = ("plus 1 650;ext=", "+1 650;ext=");
+        assert!(matches!(
+            parser::failure(original, parsed, failure(parsed, 6)),
+            error::Parse::Malformed { offset: 10 }
+        ));
+
+        assert!(matches!(
+            parser::failure(
+                input,
+                input,
+                nom::Err::Error(nom::error::Error::new(input, nom::error::ErrorKind::Verify))
+            ),
+            error::Parse::NoNumber
+        ));
+        assert!(matches!(
+            parser::parse(None, "no number"),
+            Err(error::Parse::NoNumber)
+        ));
+    }
+
+    #[test]
+    fn extension_forms() {
+        for input in [
+            "650 253 0000;ext=123",
+            "650 253 0000 ext. 123",
+            "650 253 0000 x123",
+            "650 253 0000 \u{FF58}123",
+            "650 253 0000 \u{FF45}\u{FF58}\u{FF54}123",
+            "650 253 0000 \u{FF45}\u{FF58}\u{FF54}\u{FF4E}\u{FF0E} 123",
+            "650 253 0000 \u{FF49}\u{FF4E}\u{FF54} 123",
+            "650 253 0000 \u{FF03}123",
+        ] {
+            let number = parser::parse(Some(country::US), input).unwrap();
+
+            assert_eq!(6502530000, number.national().value(), "{input}");
+            assert_eq!(Some("123"), number.extension().map(|e| &**e), "{input}");
+        }
+
+        // An extension marker without digits is not an extension.
+        for input in [
+            "+1 650 253 0000 ext",
+            "+1 650 253 0000 ext.",
+            "+1 650 253 0000 ext. #",
+        ] {
+            let number = parser::parse(Some(country::US), input).unwrap();
+
+            assert_eq!(6502530000, number.national().value(), "{input}");
+            assert_eq!(None, number.extension(), "{input}");
+        }
+    }
+
+    #[test]
+    fn too_short_after_idd() {
+        assert!(matches!(
+            parser::parse(Some(country::US), "011 64"),
+            Err(error::Parse::TooShortAfterIdd)
+        ));
+        assert!(matches!(
+            parser::parse(Some(country::NZ), "00 64"),
+            Err(error::Parse::TooShortAfterIdd)
+        ));
+        assert!(matches!(
+            parser::parse(None, "+64"),
+            Err(error::Parse::TooShortNsn)
+        ));
+    }
+
+    #[test]
+    fn plus_and_idd() {
+        // A redundant IDD after the plus is stripped when it matches the IDD
+        // of the default region.
+        for (country, input, expected) in [
+            (country::US, "+011 64 3 331 6005", "+64 3 331 6005"),
+            (country::NZ, "+00 64 3 331 6005", "+64 3 331 6005"),
+            (country::GB, "+00 33 1 42 68 53 00", "+33 1 42 68 53 00"),
+            (country::DE, "+00 1 650 253 0000", "+1 650 253 0000"),
+            (country::AU, "+0011 44 20 7031 3000", "+44 20 7031 3000"),
+            (country::JP, "+010 1 650 253 0000", "+1 650 253 0000"),
+            (country::IT, "+ 00 49 30 1234567", "+49 30 1234567"),
+        ] {
+            let number = parser::parse(Some(country), input).unwrap();
+
+            assert_eq!(
+                parser::parse(None, expected).unwrap(),
+                number,
+                "{country:?} {input}"
+            );
+            assert_eq!(country::Source::Plus, number.code().source());
+        }
+
+        // Without a default region there's no IDD to strip.
+        assert!(parser::parse(None, "+011 64 3 331 6005").is_err());
+
+        // The IDD of another region is not stripped.
+        assert!(parser::parse(Some(country::GB), "+011 64 3 331 6005").is_err());
+
+        // Numbers after the plus that only start like the IDD are kept.
+        assert_eq!(
+            parser::parse(None, "+1 650 253 0000").unwrap(),
+            parser::parse(Some(country::US), "+1 650 253 0000").unwrap()
+        );
+    }
+
+    #[test]
+    fn spaces_and_dashes() {
+        assert_eq!(
+            parser::parse(None, "+7 495 1234567").unwrap(),
+            parser::parse(None, "+7 495 123-45-67").unwrap()
+        );
+        assert_eq!(
+            parser::parse(None, "+1 650 253 0000").unwrap(),
+            parser::parse(None, "+1 650 253-0000").unwrap()
+        );
+    }
+
+    #[test]
+    fn rfc3966_spaced_plus() {
+        let expected = parser::parse(None, "+64 3 331 6005").unwrap();
+
+        for input in [
+            "tel:+ 64-3-331-6005",
+            "tel:+-64-3-331-6005",
+            "tel:+(64)-3-331-6005",
+            "tel: +64-3-331-6005",
+            "+ 64 3 331 6005",
+        ] {
+            assert_eq!(expected, parser::parse(None, input).unwrap(), "{input}");
+        }
+
+        let number = parser::parse(None, "tel:+ 1-650-253-0000;ext=12").unwrap();
+        assert_eq!(6502530000, number.national().value());
+        assert_eq!(Some("12"), number.extension().map(|e| &**e));
+    }
+
+    #[test]
+    fn fuzz_non_ascii() {
+        // Deterministically combine ASCII and multibyte fragments, parsing must
+        // never panic on any of them.
+        let fragments = [
+            "+", "1", "4", "0", "00", "é", "\u{0663}", "\u{FF11}", "\u{FF0B}", " ", "-", "(", "x",
+            "#", ";", "tel:", ";ext=", "ß", "\u{200B}", "6", "9",
+        ];
+        let mut seed = 0x2545_f491_4f6c_dd1du64;
+
+        for _ in 0..2000 {
+            let mut input = String::new();
+
+            for _ in 0..(seed % 9 + 1) {
+                seed ^= seed << 13;
+                seed ^= seed >> 7;
+                seed ^= seed << 17;
+                input.push_str(fragments[(seed % fragments.len() as u64) as usize]);
+            }
+
+            for country in [None, Some(country::US), Some(country::DE)] {
+                if let Ok(number) = parser::parse(country, &input) {
+                    let _ = number.to_string();
+                    let _ = number.is_valid();
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn parse_strict() {
+        let expected = parser::parse(None, "+44 20 7031 3000").unwrap();
+
+        assert_eq!(expected, parser::parse_strict("+44 20 7031 3000").unwrap());
+        assert_eq!(
+            expected,
+            parser::parse_strict("tel:+44-20-7031-3000").unwrap()
+        );
+        assert_eq!(
+            expected,
+            parser::parse_strict("plus 44 20 7031 3000").unwrap()
+        );
+
+        assert!(matches!(
+            parser::parse_strict("020 7031 3000"),
+            Err(error::Parse::MissingPlus)
+        ));
+        assert!(matches!(
+            parser::parse_strict("0044 20 7031 3000"),
+            Err(error::Parse::MissingPlus)
+        ));
+        assert!(matches!(
+            parser::parse_strict("44 20 7031 3000"),
+            Err(error::Parse::MissingPlus)
+        ));
+        assert!(matches!(
+            parser::parse_strict("+999 20 7031 3000"),
+            Err(error::Parse::InvalidCountryCode)
+        ));
+        assert!(matches!(
+            parser::parse_strict("no number"),
+            Err(error::Parse::NoNumber)
+        ));
+    }
+
+    #[test]
+    fn empty_input() {
+        for input in ["", "   ", "\t\r\n", "\u{a0}\u{3000}", "\u{0}"] {
+            assert!(
+                matches!(parser::parse(None, input), Err(error::Parse::EmptyInput)),
+                "{input:?}"
+            );
+            assert!(
+                matches!(parser::parse_strict(input), Err(error::Parse::EmptyInput)),
+                "{input:?}"
+            );
+            assert!(
+                matches!(
+                    parser::parse_prefix(None, input),
+                    Err(error::Parse::EmptyInput)
+                ),
+                "{input:?}"
+            );
+        }
+
+        for input in ["abc", " - ", "tel:"] {
+            assert!(
+                !matches!(parser::parse(None, input), Err(error::Parse::EmptyInput)),
+                "{input:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn control_characters() {
+        let expected = parser::parse(Some(country::GB), "020 7031 3000").unwrap();
+
+        for input in [
+            "020\n7031 3000",
+            "020\t7031\t3000",
+            "\r\n020 7031 3000\r\n",
+            "020 70\u{0}31 3000",
+            "020 7031\u{7f} 3000",
+            "020 7031 \u{85}3000",
+        ] {
+            assert_eq!(
+                expected,
+                parser::parse(Some(country::GB), input).unwrap(),
+                "{input:?}"
+            );
+        }
+
+        let expected = parser::parse(None, "tel:+44-20-7031-3000;ext=12").unwrap();
+        assert_eq!(
+            expected,
+            parser::parse(None, "tel:+44-20-\n7031-3000;ext=\t12").unwrap()
+        );
+        assert_eq!(
+            parser::parse_strict("+44 20 7031 3000").unwrap(),
+            parser::parse_strict("+44\t20 7031\n3000").unwrap()
+        );
+        assert_eq!(
+            parser::parse(None, "plus 44 20 7031 3000").unwrap(),
+            parser::parse(None, "plus\n44 20 7031 3000").unwrap()
+        );
+    }
+
+    #[test]
+    fn invalid_extension() {
+        let number = parser::parse(None, "tel:+1-650-253-0000;ext=12-34").unwrap();
+        assert_eq!(Some("1234"), number.extension().map(|e| &**e));
+
+        let number = format!("tel:+1-650-253-0000;ext={}", "1".repeat(40));
+        assert_eq!(
+            Some(40),
+            parser::parse(None, number)
+                .unwrap()
+                .extension()
+                .map(|e| e.len())
+        );
+
+        for input in [
+            "tel:+1-650-253-0000;ext=abc",
+            "tel:+1-650-253-0000;ext=12a",
+            "tel:+1-650-253-0000;ext=1!2",
+            &format!("tel:+1-650-253-0000;ext={}", "1".repeat(41)),
+        ] {
+            assert!(
+                matches!(
+                    parser::parse(None, input),
+                    Err(error::Parse::InvalidExtension)
+                ),
+                "{input}"
+            );
+        }
+    }
+
+    #[test]
+    fn extension_only() {
+        for input in [
+            "ext. 1234",
+            "ext 1234",
+            " extension 1234",
+            "x1234",
+            "#1234",
+            "tel:;ext=1234",
+        ] {
+            for country in [None, Some(country::GB), Some(country::US)] {
+                assert!(
+                    matches!(parser::parse(country, input), Err(error::Parse::NoNumber)),
+                    "{input:?} {country:?}"
+                );
+            }
+        }
+
+        let number = parser::parse(None, "+44 20 7031 3000 ext. 1234").unwrap();
+        assert_eq!(Some("1234"), number.extension().map(|e| &**e));
+    }
+
+    #[test]
+    fn parse_valid() {
+        assert!(parser::parse_valid(None, "+44 20 7031 3000").is_ok());
+        assert!(parser::parse_valid(Some(country::NZ), "03-331 6005").is_ok());
+
+        assert!(matches!(
+            parser::parse_valid(None, "+44 791234567"),
+            Err(error::ParseOrInvalid::Invalid)
+        ));
+        assert!(matches!(
+            parser::parse_valid(None, "not a number"),
+            Err(error::ParseOrInvalid::Parse(error::Parse::NoNumber))
+        ));
+    }
+
+    #[test]
+    fn parse_with_regions() {
+        // Valid in Germany, but not in Great Britain.
+        let number =
+            parser::parse_with_regions(&[country::GB, country::DE], "030 1234567").unwrap();
+        assert_eq!(49, number.code().value());
+        assert!(!parser::parse(Some(country::GB), "030 1234567")
+            .unwrap()
+            .is_valid());
+
+        // The first valid candidate is preferred.
+        let number =
+            parser::parse_with_regions(&[country::GB, country::DE], "020 7031 3000").unwrap();
+        assert_eq!(44, number.code().value());
+
+        // Without a valid candidate, the first parseable number is returned.
+        let number =
+            parser::parse_with_regions(&[country::GB, country::DE], "0101 234567").unwrap();
+        assert_eq!(44, number.code().value());
+        assert!(!number.is_valid());
+
+        assert_eq!(
+            parser::parse(None, "+64 3 331 6005").ok(),
+            parser::parse_with_regions(&[country::GB], "+64 3 331 6005").ok()
+        );
+        assert_eq!(
+            parser::parse(None, "+64 3 331 6005").ok(),
+            parser::parse_with_regions(&[], "+64 3 331 6005").ok()
+        );
+        assert!(matches!(
+            parser::parse_with_regions(&[], "020 7031 3000"),
+            Err(error::Parse::InvalidCountryCode)
+        ));
+        assert!(matches!(
+            parser::parse_with_regions(&[country::GB], "  "),
+            Err(error::Parse::EmptyInput)
+        ));
+    }
+
+    #[test]
+    fn guess_region() {
+        assert_eq!(
+            Some(country::US),
+            parser::guess_region("650 253 0000", &[country::GB, country::US])
+        );
+        assert_eq!(
+            Some(country::US),
+            parser::guess_region("650 253 0000", &[country::CA, country::US])
+        );
+        assert_eq!(
+            Some(country::DE),
+            parser::guess_region("030 1234567", &[country::GB, country::DE])
+        );
+        assert_eq!(
+            Some(country::GB),
+            parser::guess_region("+44 20 7031 3000", &[country::US, country::GB])
+        );
+
+        // Only a possible length, but nothing better.
+        assert_eq!(
+            Some(country::GB),
+            parser::guess_region("0101 234567", &[country::GB])
+        );
+
+        assert_eq!(None, parser::guess_region("650 253 0000", &[]));
+        assert_eq!(None, parser::guess_region("1", &[country::GB]));
+        assert_eq!(None, parser::guess_region("  ", &[country::GB]));
+        assert_eq!(
+            None,
+            parser::guess_region("+44 20 7031 3000", &[country::US])
+        );
+    }
+
+    #[test]
+    fn parse_e164() {
+        for meta in DATABASE.iter() {
+            let examples = [Type::FixedLine, Type::Mobile, Type::TollFree]
+                .iter()
+                .filter_map(|&kind| meta.descriptors().get(kind).and_then(|d| d.example()));
+
+            for example in examples {
+                let number = match meta.id().parse() {
+                    Ok(country) => parser::parse(Some(country), example),
+                    Err(_) => parser::parse(None, format!("+{}{}", meta.country_code(), example)),
+                };
+
+                if let Ok(number) = number {
+                    let e164 = number.format().to_string();
+                    let parsed = parser::parse_e164(&e164).unwrap();
+
+                    assert_eq!(parser::parse(None, &e164).unwrap(), parsed, "{}", e164);
+                    assert_eq!(number, parsed, "{}", e164);
+                    assert_eq!(country::Source::Plus, parsed.code().source());
+                }
+            }
+        }
+
+        assert!(matches!(
+            parser::parse_e164(""),
+            Err(error::Parse::EmptyInput)
+        ));
+        assert!(matches!(
+            parser::parse_e164("+"),
+            Err(error::Parse::NoNumber)
+        ));
+        assert!(matches!(
+            parser::parse_e164("442070313000"),
+            Err(error::Parse::MissingPlus)
+        ));
+        assert!(matches!(
+            parser::parse_e164(" +442070313000"),
+            Err(error::Parse::MissingPlus)
+        ));
+        assert!(matches!(
+            parser::parse_e164("+44-20-7031-3000"),
+            Err(error::Parse::Malformed { offset: 3 })
+        ));
+        assert!(matches!(
+            parser::parse_e164("+442070313000\n"),
+            Err(error::Parse::Malformed { offset: 13 })
+        ));
+        assert!(matches!(
+            parser::parse_e164("+\u{ff14}\u{ff14}2070313000"),
+            Err(error::Parse::Malformed { offset: 1 })
+        ));
+        assert!(matches!(
+            parser::parse_e164("+0442070313000"),
+            Err(error::Parse::InvalidCountryCode)
+        ));
+        for short in ["+4", "+44", "+441"] {
+            assert_eq!(
+                format!("{:?}", parser::parse(None, short)),
+                format!("{:?}", parser::parse_e164(short))
+            );
+        }
+    }
+
+    #[test]
+    fn parse_all() {
+        let strings = ["+44 20 7031 3000", "020 7031 3000", "+4420", "foo"];
+        let numbers = parser::parse_all(Some(country::GB), strings);
+
+        assert_eq!(strings.len(), numbers.len());
+        for (string, number) in strings.iter().zip(&numbers) {
+            assert_eq!(
+                parser::parse(Some(country::GB), string).ok().as_ref(),
+                number.as_ref().ok(),
+                "{string}"
+            );
+        }
+
+        assert!(parser::parse_all(None, Vec::<String>::new()).is_empty());
+    }
+
+    #[cfg(feature = "rayon")]
+    #[test]
+    fn par_parse_all() {
+        let strings = (0..1000)
+            .map(|i| format!("+44 20 7031 {i:04}"))
+            .collect::<Vec<_>>();
+
+        let ok = |numbers: Vec<Result<_, _>>| {
+            numbers
+                .into_iter()
+                .map(Result::ok)
+                .collect::<Vec<Option<_>>>()
+        };
+
+        assert_eq!(
+            ok(parser::parse_all(None, &strings)),
+            ok(parser::par_parse_all(None, &strings))
+        );
+    }
+
+    #[test]
+    fn keep_groups() {
+        let groups = |country, string| {
+            parser::parse_with_options(
+                &DATABASE,
+                country,
+                string,
+                ParseOptions::new().keep_groups(true),
+            )
+            .unwrap()
+            .original_groups()
+            .map(<[u8]>::to_vec)
+        };
+
+        assert_eq!(Some(vec![2, 6]), groups(None, "+44 20 7031 3000"));
+        assert_eq!(Some(vec![2, 6]), groups(None, "+44 (0)20 7031 3000"));
+        assert_eq!(Some(vec![2, 6]), groups(Some(country::GB), "020 7031 3000"));
+        assert_eq!(Some(vec![2, 6]), groups(None, "tel:+44-20-7031-3000"));
+        assert_eq!(Some(vec![3, 6]), groups(None, "+1 (650) 253-0000"));
+        assert_eq!(Some(vec![2, 6]), groups(None, "+39 06 1234 5678"));
+        assert_eq!(Some(vec![]), groups(None, "+442070313000"));
+
+        // Not kept by default, and not considered when comparing numbers.
+        let number = parser::parse(None, "+44 20 7031 3000").unwrap();
+        assert_eq!(None, number.original_groups());
+        assert_eq!(
+            number,
+            parser::parse_with_options(
+                &DATABASE,
+                None,
+                "+44 207 031 3000",
+                ParseOptions::new().keep_groups(true)
+            )
+            .unwrap()
+        );
+    }
+
+    #[test]
+    fn spelled_plus() {
+        let expected = parser::parse(None, "+44 20 7031 3000").unwrap();
+
+        assert_eq!(
+            expected,
+            parser::parse(None, "plus 44 20 7031 3000").unwrap()
+        );
+        assert_eq!(
+            expected,
+            parser::parse(Some(country::US), "Plus 44 20 7031 3000").unwrap()
+        );
+        assert_eq!(
+            expected,
+            parser::parse(None, " PLUS44 20 7031 3000").unwrap()
+        );
+    }
+
     #[test]
     fn issue_43() {
         let res = parser::parse(None, " 2 22#:");
@@ -18,34 +18,65 @@ use nom::error::ErrorKind;
 use nom::IResult;
 
 pub fn phone_number(i: &str) -> IResult<&str, Number<'_>> {
-    let (_, i) = extract(i)?;
-    let extension = consts::EXTN_PATTERN.captures(i);
-
-    if let Some(c) = extension.as_ref() {
-        if c.get(0).is_none() || c.get(2).is_none() {
-            return Err(nom::Err::Failure(nom::error::Error::new(i, ErrorKind::Eof)));
+    // An extension with no number before it (e.g. `"ext. 1234"`) would be
+    // taken as the number itself by `extract`, so reject it.
+    if let Some(extension) = consts::EXTN_PATTERN.find(i) {
+        if !consts::VALID_START_CHAR.is_match(&i[..extension.start()]) {
+            return Err(nom::Err::Error(nom::error::Error::new(
+                i,
+                ErrorKind::RegexpMatch,
+            )));
         }
     }
 
+    let (_, i) = extract(i)?;
+
+    // Every way of writing an extension captures its digits in its own group,
+    // so take the first non-empty one; a marker without digits is dropped.
+    let (national, extension) = match consts::EXTN_PATTERN.captures(i) {
+        Some(c) => (
+            &i[..c.get(0).unwrap().start()],
+            (1..c.len())
+                .filter_map(|group| c.get(group))
+                .find(|m| !m.as_str().is_empty())
+                .map(|m| m.as_str()),
+        ),
+
+        None => (i, None),
+    };
+
     Ok((
         "",
         Number {
-            national: extension
-                .as_ref()
-                .map(|c| &i[..c.get(0).unwrap().start()])
-                .unwrap_or(i)
-                .into(),
+            national: national.into(),
 
-            extension: extension
-                .as_ref()
-                .map(|c| c.get(2).unwrap().as_str())
-                .map(Into::into),
+            extension: extension.map(Into::into),
 
             ..Default::default()
         },
     ))
 }
 
+/// Parse a phone number at the start of the input, leaving any text after it
+/// unconsumed.
+pub fn phone_number_prefix(i: &str) -> IResult<&str, Number<'_>> {
+    let start = consts::VALID_START_CHAR
+        .find(i)
+        .map(|m| m.start())
+        .ok_or_else(|| nom::Err::Error(nom::error::Error::new(i, ErrorKind::RegexpMatch)))?;
+    let candidate = consts::PHONE_NUMBER_PREFIX
+        .find(&i[start..])
+        .map(|m| &i[start..start + m.end()])
+        .ok_or_else(|| nom::Err::Error(nom::error::Error::new(i, ErrorKind::RegexpMatch)))?;
+    let end = consts::UNWANTED_END_CHARS
+        .find(candidate)
+        .map(|m| m.start())
+        .unwrap_or(candidate.len());
+
+    let (_, number) = phone_number(&candidate[..end])?;
+    Ok((&i[start + end..], number))
+}
+
 #[cfg(test)]
 mod test {
     use crate::parser::helper::*;
@@ -63,4 +94,34 @@ mod test {
             }
         );
     }
+
+    #[test]
+    fn phone_number_prefix() {
+        assert_eq!(
+            natural::phone_number_prefix("650 253 0000 extn. 4567, ask for Bob").unwrap(),
+            (
+                ", ask for Bob",
+                Number {
+                    national: "650 253 0000".into(),
+                    extension: Some("4567".into()),
+
+                    ..Default::default()
+                }
+            )
+        );
+
+        assert_eq!(
+            natural::phone_number_prefix("Tel: +44 20 7031 3000 is the office").unwrap(),
+            (
+                " is the office",
+                Number {
+                    national: "+44 20 7031 3000".into(),
+
+                    ..Default::default()
+                }
+            )
+        );
+
+        assert!(natural::phone_number_prefix("no number here").is_err());
+    }
 }
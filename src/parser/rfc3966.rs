@@ -12,6 +12,7 @@
 // See the License for the specific language governing permissions and
 // limitations under the License.
 
+use crate::consts;
 use crate::parser::helper::*;
 use fnv::FnvHashMap;
 use nom::{
@@ -26,40 +27,85 @@ use nom::{
 pub fn phone_number(i: &str) -> IResult<&str, Number<'_>> {
     parse! { i =>
         opt(tag_no_case("Tel:"));
+        space0;
         let prefix = opt(prefix);
-        let national = take_while1(number);
+        let national = national;
         check;
         let params = opt(parameters);
     };
 
+    // Only digits and visual separators are allowed in an extension, and ITU
+    // caps them at 40 digits.
+    if let Some(&ext) = params.as_ref().and_then(|m| m.get("ext")) {
+        if !ext.chars().all(|c| c.is_wide_digit() || separator(c))
+            || ext.chars().filter(|c| c.is_wide_digit()).count() > consts::MAX_LENGTH_FOR_EXTENSION
+        {
+            return Err(nom::Err::Failure(make_error(ext, ErrorKind::Digit)));
+        }
+    }
+
+    let context = params
+        .as_ref()
+        .and_then(|m| m.get("phone-context"))
+        .copied();
+    if let Some(context) = context {
+        if !global_number(context) && !domain_name(context) {
+            return Err(nom::Err::Failure(make_error(context, ErrorKind::Verify)));
+        }
+    }
+
+    // A local number with a global number context is that number followed by
+    // the local digits, e.g. `331-6005;phone-context=+64-3` is `+64-3-331-6005`.
+    // Domain name contexts don't say anything about the number, so they're
+    // left to the default country, and global numbers ignore the context.
+    let national = match context {
+        Some(context) if prefix.is_none() && context.starts_with('+') => {
+            format!("{context}{national}").into()
+        }
+
+        _ => (*national).into(),
+    };
+
     Ok((
         i,
         Number {
-            national: (*national).into(),
-
-            prefix: prefix
-                .or_else(|| {
-                    params
-                        .as_ref()
-                        .and_then(|m| m.get("phone-context"))
-                        .map(|&s| s.strip_prefix('+').unwrap_or(s))
-                })
-                .map(|cs| cs.into()),
+            national,
+            prefix: prefix.map(|cs| cs.into()),
 
             extension: params
                 .as_ref()
                 .and_then(|m| m.get("ext"))
                 .map(|&cs| cs.into()),
 
+            subaddress: params
+                .as_ref()
+                .and_then(|m| m.get("isub"))
+                .filter(|s| !s.is_empty())
+                .map(|&cs| cs.into()),
+
             ..Default::default()
         },
     ))
 }
 
+fn national(i: &str) -> IResult<&str, &str> {
+    // Parameters without a number before them can only be a malformed URI, so
+    // don't let the natural parser pick digits out of them.
+    if i.starts_with(';') {
+        return Err(nom::Err::Failure(make_error(i, ErrorKind::TakeWhile1)));
+    }
+
+    take_while1(number)(i)
+}
+
 fn prefix(i: &str) -> IResult<&str, &str> {
     parse! { i =>
         char('+');
-        take_till1(separator)
+        // Be as lenient as the natural parser about punctuation after the plus.
+        take_while(|c: char| c.is_whitespace() || separator(c));
+        // Only digits, otherwise a natural number with groups separated by
+        // both spaces and dashes (e.g. "+7 495 123-45-67") ends up in here.
+        take_while1(|c: char| c.is_wide_digit())
     }
 }
 
@@ -82,6 +128,34 @@ fn parameter(i: &str) -> IResult<&str, (&str, &str)> {
     Ok((i, (key, value)))
 }
 
+/// Check for a `global-number-digits` context, a `+` followed by digits and
+/// visual separators.
+fn global_number(context: &str) -> bool {
+    context.strip_prefix('+').map_or(false, |digits| {
+        digits.chars().all(|c| c.is_ascii_digit() || separator(c))
+            && digits.chars().any(|c| c.is_ascii_digit())
+    })
+}
+
+/// Check for a `domainname` context, dot separated labels of alphanumerics and
+/// inner dashes, the last one starting with a letter.
+fn domain_name(context: &str) -> bool {
+    let context = context.strip_suffix('.').unwrap_or(context);
+    let label = |label: &str| {
+        !label.is_empty()
+            && !label.starts_with('-')
+            && !label.ends_with('-')
+            && label.chars().all(|c| c.is_ascii_alphanumeric() || c == '-')
+    };
+
+    context.split('.').all(label)
+        && context
+            .rsplit('.')
+            .next()
+            .and_then(|top| top.chars().next())
+            .map_or(false, |c| c.is_ascii_alphabetic())
+}
+
 fn check(i: &str) -> IResult<&str, ()> {
     if i.is_empty() || i.as_bytes()[0] == b';' {
         Ok((i, ()))
@@ -142,8 +216,7 @@ mod test {
                 .unwrap()
                 .1,
             Number {
-                national: "2034567890".into(),
-                prefix: Some("44".into()),
+                national: "+442034567890".into(),
                 extension: Some("456".into()),
 
                 ..Default::default()
@@ -162,6 +235,95 @@ mod test {
                 ..Default::default()
             }
         );
+
+        assert_eq!(
+            rfc3966::phone_number("tel:+1-520-878-2491;isub=1234;ext=123")
+                .unwrap()
+                .1,
+            Number {
+                national: "-520-878-2491".into(),
+                prefix: Some("1".into()),
+                extension: Some("123".into()),
+                subaddress: Some("1234".into()),
+
+                ..Default::default()
+            }
+        );
+    }
+
+    #[test]
+    fn phone_context() {
+        let national = |input| rfc3966::phone_number(input).map(|(_, n)| n.national);
+
+        assert_eq!(
+            Ok("+64-3331-6005".into()),
+            national("tel:331-6005;phone-context=+64-3")
+        );
+        assert_eq!(
+            Ok("-3-331-6005".into()),
+            national("tel:+64-3-331-6005;phone-context=+1")
+        );
+        assert_eq!(
+            Ok("03-331-6005".into()),
+            national("tel:03-331-6005;phone-context=example.com")
+        );
+        assert_eq!(
+            Ok("03-331-6005".into()),
+            national("tel:03-331-6005;phone-context=a-1.example.com.")
+        );
+
+        for context in [
+            "",
+            "+",
+            "+-",
+            "+64a",
+            "example..com",
+            "-example.com",
+            "example.1com",
+            "exa_mple.com",
+        ] {
+            assert!(
+                matches!(
+                    rfc3966::phone_number(&format!("tel:03-331-6005;phone-context={context}")),
+                    Err(nom::Err::Failure(_))
+                ),
+                "{context:?}"
+            );
+        }
+    }
+
+    #[test]
+    fn spaced_prefix() {
+        for input in [
+            "tel:+64-3-331-6005",
+            "tel:+ 64-3-331-6005",
+            "tel:+-64-3-331-6005",
+            "tel:+(64)-3-331-6005",
+            "tel: +64-3-331-6005",
+        ] {
+            let number = rfc3966::phone_number(input).unwrap().1;
+            assert_eq!(Some("64".into()), number.prefix, "{input}");
+        }
+    }
+
+    #[test]
+    fn natural_groups() {
+        // Spaces are not allowed in RFC3966, so a natural number with both
+        // spaces and dashes is left to the natural parser.
+        assert!(rfc3966::phone_number("+7 495 123-45-67").is_err());
+        assert!(rfc3966::phone_number("+1 650 253-0000").is_err());
+    }
+
+    #[test]
+    fn no_number() {
+        assert!(matches!(
+            rfc3966::phone_number("tel:;phone-context=+1"),
+            Err(nom::Err::Failure(_))
+        ));
+        assert!(matches!(
+            rfc3966::phone_number(";ext=1234"),
+            Err(nom::Err::Failure(_))
+        ));
     }
 
     #[test]
@@ -13,6 +13,7 @@
 // limitations under the License.
 
 use crate::carrier::Carrier;
+use crate::consts;
 use crate::country;
 use crate::error;
 use crate::extension::Extension;
@@ -23,12 +24,23 @@ use crate::parser;
 use crate::validator;
 use either::*;
 use serde_derive::{Deserialize, Serialize};
+use std::cmp::Ordering;
 use std::fmt;
+use std::hash::{Hash, Hasher};
 use std::ops::Deref;
 use std::str::FromStr;
+use strum::{Display, EnumString};
 
 /// A phone number.
-#[derive(Clone, Eq, PartialEq, Serialize, Deserialize, Hash, Debug)]
+///
+/// Phone numbers are ordered by country code, national number value, number of
+/// leading zeros, extension, carrier and subaddress, in that order. Numbers
+/// without an extension, carrier or subaddress sort before numbers with one.
+///
+/// The source of the country code only tells how the number was written, so
+/// it's not considered when comparing or hashing numbers: `"+64 3 331 6005"`
+/// and `"03 331 6005"` parsed for New Zealand are equal.
+#[derive(Clone, Serialize, Deserialize, Debug)]
 pub struct PhoneNumber {
     /// The country calling code for this number, as defined by the International
     /// Telecommunication Union (ITU). For example, this would be 1 for NANPA
@@ -69,15 +81,72 @@ pub struct PhoneNumber {
     /// Note this is the "preferred" code, which means other codes may work as
     /// well.
     pub(crate) carrier: Option<Carrier>,
+
+    /// The ISDN subaddress of the phone number, as given by the `isub`
+    /// parameter of an RFC3966 `tel:` URI.
+    #[serde(default)]
+    pub(crate) subaddress: Option<String>,
+
+    /// The boundaries between the groups of digits of the national number as
+    /// it was written, only kept when requested in the `ParseOptions`.
+    ///
+    /// They're not serialized and not considered when comparing numbers.
+    #[serde(skip)]
+    pub(crate) groups: Option<Box<[u8]>>,
+}
+
+impl PartialEq for PhoneNumber {
+    fn eq(&self, other: &Self) -> bool {
+        self.code.value == other.code.value
+            && self.national == other.national
+            && self.extension == other.extension
+            && self.carrier == other.carrier
+            && self.subaddress == other.subaddress
+    }
+}
+
+impl Eq for PhoneNumber {}
+
+impl Hash for PhoneNumber {
+    fn hash<H: Hasher>(&self, state: &mut H) {
+        self.code.value.hash(state);
+        self.national.hash(state);
+        self.extension.hash(state);
+        self.carrier.hash(state);
+        self.subaddress.hash(state);
+    }
+}
+
+impl Ord for PhoneNumber {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.code
+            .value
+            .cmp(&other.code.value)
+            .then_with(|| self.national.cmp(&other.national))
+            .then_with(|| self.extension.cmp(&other.extension))
+            .then_with(|| self.carrier.cmp(&other.carrier))
+            .then_with(|| self.subaddress.cmp(&other.subaddress))
+    }
+}
+
+impl PartialOrd for PhoneNumber {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
 }
 
 /// Wrapper to make it easier to access information about the country of a
 /// phone number.
-pub struct Country<'a>(&'a PhoneNumber);
+pub struct Country<'a>(&'a PhoneNumber, &'a Database);
 
 /// The phone number type.
-#[derive(Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Hash, Debug)]
+///
+/// Types are displayed as their snake case name (e.g. `"fixed_line"` or
+/// `"toll_free"`), the same used by serde, and parsed from it ignoring ASCII
+/// case.
+#[derive(Copy, Clone, Eq, PartialEq, Serialize, Deserialize, Hash, Debug, Display, EnumString)]
 #[serde(rename_all = "snake_case")]
+#[strum(serialize_all = "snake_case", ascii_case_insensitive)]
 pub enum Type {
     /// Fixed line numbers.
     FixedLine,
@@ -147,6 +216,22 @@ impl FromStr for PhoneNumber {
     }
 }
 
+impl TryFrom<&str> for PhoneNumber {
+    type Error = error::Parse;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        parser::parse(None, s)
+    }
+}
+
+impl TryFrom<String> for PhoneNumber {
+    type Error = error::Parse;
+
+    fn try_from(s: String) -> Result<Self, Self::Error> {
+        parser::parse(None, s)
+    }
+}
+
 impl fmt::Display for PhoneNumber {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", self.format())
@@ -154,9 +239,122 @@ impl fmt::Display for PhoneNumber {
 }
 
 impl PhoneNumber {
+    /// Build a phone number from its country code and national number,
+    /// without going through the parser.
+    ///
+    /// The `zeros` are the significant leading zeros of the national number,
+    /// see [`NationalNumber::zeros`]. The country code has to be known to the
+    /// default database.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::{Mode, PhoneNumber};
+    ///
+    /// let number = PhoneNumber::from_parts(39, 612345678, 1)
+    ///     .unwrap()
+    ///     .with_extension("123");
+    ///
+    /// assert_eq!(
+    ///     "+39 06 1234 5678 ext. 123",
+    ///     number.format().mode(Mode::International).to_string()
+    /// );
+    /// ```
+    pub fn from_parts(code: u16, national: u64, zeros: u8) -> Result<Self, error::Parse> {
+        Self::from_parts_with(&DATABASE, code, national, zeros)
+    }
+
+    /// Build a phone number from its parts with the given `Database`.
+    pub fn from_parts_with(
+        database: &Database,
+        code: u16,
+        national: u64,
+        zeros: u8,
+    ) -> Result<Self, error::Parse> {
+        if database.by_code(&code).is_none() {
+            return Err(error::Parse::InvalidCountryCode);
+        }
+
+        Ok(PhoneNumber {
+            code: country::Code {
+                value: code,
+                source: country::Source::Number,
+            },
+
+            national: NationalNumber::new(national, zeros)?,
+
+            extension: None,
+            carrier: None,
+            subaddress: None,
+            groups: None,
+        })
+    }
+
+    /// Get a copy of the phone number without the details of how it was
+    /// parsed, so that numbers of the same subscriber are identical however
+    /// they were written, not only equal.
+    ///
+    /// The country code source becomes [`Source::Plus`](country::Source::Plus),
+    /// as if the number was parsed from its E.164 format, and the original
+    /// groups are dropped; the extension, carrier and subaddress are kept.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::country;
+    ///
+    /// let national = phonenumber::parse(Some(country::GB), "020 7031 3000").unwrap();
+    /// let international = phonenumber::parse(None, "+44 20 7031 3000").unwrap();
+    ///
+    /// assert_eq!(national, international);
+    /// assert_ne!(national.code().source(), international.code().source());
+    /// assert_eq!(
+    ///     national.canonical().code().source(),
+    ///     international.canonical().code().source()
+    /// );
+    /// ```
+    pub fn canonical(&self) -> PhoneNumber {
+        PhoneNumber {
+            code: country::Code {
+                value: self.code.value,
+                source: country::Source::Plus,
+            },
+
+            national: self.national,
+            extension: self.extension.clone(),
+            carrier: self.carrier.clone(),
+            subaddress: self.subaddress.clone(),
+            groups: None,
+        }
+    }
+
+    /// Set the extension of the phone number.
+    pub fn with_extension<T: Into<Extension>>(mut self, extension: T) -> Self {
+        self.extension = Some(extension.into());
+        self
+    }
+
+    /// Set the preferred domestic carrier code of the phone number.
+    pub fn with_carrier<T: Into<Carrier>>(mut self, carrier: T) -> Self {
+        self.carrier = Some(carrier.into());
+        self
+    }
+
+    /// Set the ISDN subaddress of the phone number.
+    pub fn with_subaddress<T: Into<String>>(mut self, subaddress: T) -> Self {
+        self.subaddress = Some(subaddress.into());
+        self
+    }
+
     /// Get information about the country for the phone number.
     pub fn country(&self) -> Country<'_> {
-        Country(self)
+        Country(self, *DATABASE)
+    }
+
+    /// Get information about the country for the phone number, as found in
+    /// the given `Database`.
+    pub fn country_with<'a>(&'a self, database: &'a Database) -> Country<'a> {
+        Country(self, database)
     }
 
     /// Get the country code.
@@ -169,6 +367,27 @@ impl PhoneNumber {
         &self.national
     }
 
+    /// Check if the national number has leading zeros which are part of the
+    /// number itself, and have to be kept when dialling internationally, like
+    /// the `0` in the Italian `+39 06 1234 5678`.
+    ///
+    /// Such numbers can not be stored as a plain integer without losing the
+    /// zeros, see [`NationalNumber::zeros`].
+    pub fn has_significant_leading_zeros(&self) -> bool {
+        self.national.zeros() > 0
+    }
+
+    /// Get the boundaries between the groups of digits of the national number
+    /// as it was written, if they were kept when parsing, see
+    /// [`ParseOptions::keep_groups`](crate::ParseOptions::keep_groups).
+    ///
+    /// Every boundary is the offset into the national number, including any
+    /// leading zeros, where a group starts; e.g. `[2, 6]` for a number written
+    /// as `"+44 20 7031 3000"`.
+    pub fn original_groups(&self) -> Option<&[u8]> {
+        self.groups.as_deref()
+    }
+
     /// Get the extension.
     pub fn extension(&self) -> Option<&Extension> {
         self.extension.as_ref()
@@ -179,6 +398,27 @@ impl PhoneNumber {
         self.carrier.as_ref()
     }
 
+    /// Get the ISDN subaddress, only found in numbers parsed from an RFC3966
+    /// `tel:` URI with an `isub` parameter.
+    ///
+    /// It is kept when formatting in [`Mode::Rfc3966`](crate::Mode::Rfc3966).
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::Mode;
+    ///
+    /// let number = phonenumber::parse(None, "tel:+1-520-878-2491;isub=1234").unwrap();
+    /// assert_eq!(Some("1234"), number.subaddress());
+    /// assert_eq!(
+    ///     "tel:+1-520-878-2491;isub=1234",
+    ///     number.format().mode(Mode::Rfc3966).to_string()
+    /// );
+    /// ```
+    pub fn subaddress(&self) -> Option<&str> {
+        self.subaddress.as_deref()
+    }
+
     /// Prepare a formatter for this `PhoneNumber`.
     ///
     /// # Example
@@ -203,12 +443,123 @@ impl PhoneNumber {
         formatter::format_with(database, self)
     }
 
+    /// Format the phone number in E.164 format, e.g. `"+16502530000"`.
+    ///
+    /// This is a shortcut for `self.format().mode(Mode::E164).to_string()`.
+    pub fn to_e164(&self) -> String {
+        self.format().mode(formatter::Mode::E164).to_string()
+    }
+
+    /// Format the phone number in international format, e.g.
+    /// `"+1 650-253-0000"`.
+    ///
+    /// This is a shortcut for `self.format().mode(Mode::International).to_string()`.
+    pub fn to_international(&self) -> String {
+        self.format()
+            .mode(formatter::Mode::International)
+            .to_string()
+    }
+
+    /// Format the phone number in E.123 international notation, e.g.
+    /// `"+1 650 253 0000"`.
+    ///
+    /// This is a shortcut for `self.format().mode(Mode::E123).to_string()`.
+    pub fn to_e123(&self) -> String {
+        self.format().mode(formatter::Mode::E123).to_string()
+    }
+
+    /// Format the phone number in national format, e.g. `"(650) 253-0000"`.
+    ///
+    /// This is a shortcut for `self.format().mode(Mode::National).to_string()`.
+    pub fn to_national(&self) -> String {
+        self.format().mode(formatter::Mode::National).to_string()
+    }
+
+    /// Get the groups of digits of the phone number as formatted in the given
+    /// mode, without the country code or extension.
+    ///
+    /// The national modes include the national prefix or carrier code in the
+    /// groups, the international ones don't; `E164` and `NationalRaw` have no
+    /// grouping, so they give the whole national number as a single group.
+    /// Numbers with an unknown country code have no groups.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::Mode;
+    ///
+    /// let number = phonenumber::parse(None, "+44 20 7031 3000").unwrap();
+    ///
+    /// assert_eq!(vec!["20", "7031", "3000"], number.format_groups(Mode::International));
+    /// assert_eq!(vec!["020", "7031", "3000"], number.format_groups(Mode::National));
+    /// ```
+    pub fn format_groups(&self, mode: formatter::Mode) -> Vec<String> {
+        self.format_groups_with(&DATABASE, mode)
+    }
+
+    /// Get the groups of digits of the phone number as formatted in the given
+    /// mode with the given `Database`, without the country code or extension.
+    pub fn format_groups_with(&self, database: &Database, mode: formatter::Mode) -> Vec<String> {
+        let national = match mode {
+            formatter::Mode::E164 | formatter::Mode::NationalRaw => formatter::Mode::NationalRaw,
+            formatter::Mode::National | formatter::Mode::E123National => formatter::Mode::National,
+
+            formatter::Mode::International | formatter::Mode::E123 | formatter::Mode::Rfc3966 => {
+                formatter::Mode::International
+            }
+        };
+
+        let mut formatted = String::new();
+        let formatter = self.format_with(database).mode(national).extension(false);
+        if formatter.write_to(&mut formatted).is_err() {
+            return Vec::new();
+        }
+
+        // Only the international format starts with the country code.
+        let formatted = if national == formatter::Mode::International {
+            let code = format!("+{}", self.code.value());
+            formatted[code.len()..].to_owned()
+        } else {
+            formatted
+        };
+
+        consts::SEPARATOR_PATTERN
+            .split(&formatted)
+            .filter(|group| !group.is_empty())
+            .map(str::to_owned)
+            .collect()
+    }
+
+    /// Format the phone number in national format, dialled through the given
+    /// carrier selection code instead of the number's own carrier, e.g.
+    /// `"0 15 (21) 3121-2869"` for a Brazilian number and the carrier `"15"`.
+    ///
+    /// The code is placed according to the carrier code formatting rule of the
+    /// region, numbers of regions without one and an empty code are formatted
+    /// without it.
+    pub fn format_with_carrier(&self, carrier_code: &str) -> String {
+        if carrier_code.is_empty() {
+            return self.to_national();
+        }
+
+        let mut number = self.clone();
+        number.carrier = Some(carrier_code.into());
+        number.to_national()
+    }
+
+    /// Format the phone number in national format, dialled through the number's
+    /// own carrier selection code if it has one, otherwise through the given
+    /// fallback, see [`format_with_carrier`](Self::format_with_carrier).
+    pub fn format_with_preferred_carrier(&self, fallback: &str) -> String {
+        self.format_with_carrier(self.carrier().map_or(fallback, |c| c))
+    }
+
     /// Get the metadata that applies to this phone number from the given
     /// database.
     pub fn metadata<'a>(&self, database: &'a Database) -> Option<&'a Metadata> {
-        match validator::source_for(database, self.code.value(), &self.national.to_string())? {
+        match validator::source_for(database, self.code.value(), &self.national.digits())? {
             Left(region) => database.by_id(region.as_ref()),
-            Right(code) => database.by_code(&code).and_then(|m| m.into_iter().next()),
+            Right(code) => database.main_metadata(code),
         }
     }
 
@@ -222,13 +573,220 @@ impl PhoneNumber {
         validator::is_valid_with(database, self)
     }
 
+    /// Check if the phone number has a possible length for its country,
+    /// without the more expensive matching done by [`is_valid`](Self::is_valid).
+    pub fn is_possible(&self) -> bool {
+        validator::is_possible(self)
+    }
+
+    /// Check if the phone number has a possible length for its country with
+    /// the given `Database`.
+    pub fn is_possible_with(&self, database: &Database) -> bool {
+        validator::is_possible_with(database, self)
+    }
+
+    /// Check if the phone number has a possible length for its country,
+    /// returning how the length compares to the possible ones, see
+    /// [`Validation`](crate::Validation).
+    pub fn is_possible_reason(&self) -> validator::Validation {
+        validator::is_possible_reason(self)
+    }
+
+    /// Check if the phone number has a possible length for its country with
+    /// the given `Database`, returning how the length compares to the possible
+    /// ones.
+    pub fn is_possible_reason_with(&self, database: &Database) -> validator::Validation {
+        validator::is_possible_reason_with(database, self)
+    }
+
+    /// Check if the length of the phone number is possible for its type, see
+    /// [`Validation`](crate::Validation).
+    pub fn validate(&self) -> validator::Validation {
+        validator::validate(self)
+    }
+
+    /// Check if the length of the phone number is possible for its type with
+    /// the given `Database`.
+    pub fn validate_with(&self, database: &Database) -> validator::Validation {
+        validator::validate_with(database, self)
+    }
+
+    /// The possible lengths of national numbers of the same type as this one,
+    /// sorted, e.g. to tell how many digits are missing from a partially
+    /// entered number.
+    ///
+    /// If the type of the number is unknown, these are the lengths of any
+    /// type of number of its country.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let number = phonenumber::parse(None, "+44 7912 345678").unwrap();
+    /// assert_eq!(vec![10], number.possible_lengths());
+    ///
+    /// let number = phonenumber::parse(None, "+44 79123").unwrap();
+    /// assert_eq!(vec![7, 9, 10], number.possible_lengths());
+    /// ```
+    pub fn possible_lengths(&self) -> Vec<u16> {
+        self.possible_lengths_with(&DATABASE)
+    }
+
+    /// The possible lengths of national numbers of the same type as this one
+    /// with the given `Database`.
+    pub fn possible_lengths_with(&self, database: &Database) -> Vec<u16> {
+        let main = || database.main_metadata(self.code.value());
+
+        let meta = match self.metadata(database).or_else(main) {
+            Some(meta) => meta,
+            None => return Vec::new(),
+        };
+
+        match validator::number_type(meta, &self.national.digits()) {
+            Type::Unknown => validator::all_lengths(meta).0,
+            kind => meta.possible_lengths(kind).to_vec(),
+        }
+    }
+
+    /// The possible lengths of national numbers of the given type in the
+    /// country of this number, see [`Metadata::possible_lengths`].
+    pub fn possible_lengths_for(&self, kind: Type) -> &'static [u16] {
+        self.possible_lengths_for_with(*DATABASE, kind)
+    }
+
+    /// The possible lengths of national numbers of the given type in the
+    /// country of this number with the given `Database`.
+    pub fn possible_lengths_for_with<'d>(&self, database: &'d Database, kind: Type) -> &'d [u16] {
+        self.metadata(database)
+            .or_else(|| database.main_metadata(self.code.value()))
+            .map_or(&[], |meta| meta.possible_lengths(kind))
+    }
+
+    /// Check if the phone number is valid and belongs to the given region.
+    pub fn is_valid_for_region(&self, region: country::Id) -> bool {
+        validator::is_valid_for_region(self, region)
+    }
+
+    /// Check if the phone number is valid and belongs to the given region with
+    /// the given `Database`.
+    pub fn is_valid_for_region_with(&self, database: &Database, region: country::Id) -> bool {
+        validator::is_valid_for_region_with(database, self, region)
+    }
+
     /// Determine the [`Type`] of the phone number.
     pub fn number_type(&self, database: &Database) -> Type {
         match self.metadata(database) {
-            Some(metadata) => validator::number_type(metadata, &self.national.value.to_string()),
+            Some(metadata) => validator::number_type(metadata, &self.national.digits()),
             None => Type::Unknown,
         }
     }
+
+    /// Get every [`Type`] whose pattern the phone number matches, not just the
+    /// first one like [`number_type`](Self::number_type).
+    ///
+    /// This helps understand why a number is classified the way it is when
+    /// the patterns of several types overlap.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::Type;
+    ///
+    /// let number = phonenumber::parse(None, "+1 650 253 0000").unwrap();
+    /// assert_eq!(vec![Type::FixedLine, Type::Mobile], number.matching_types());
+    /// ```
+    pub fn matching_types(&self) -> Vec<Type> {
+        self.matching_types_with(&DATABASE)
+    }
+
+    /// Get every [`Type`] whose pattern the phone number matches with the given
+    /// `Database`.
+    pub fn matching_types_with(&self, database: &Database) -> Vec<Type> {
+        match self.metadata(database) {
+            Some(metadata) => validator::matching_types(metadata, &self.national.digits()),
+            None => Vec::new(),
+        }
+    }
+
+    /// Check if the region of the phone number supports mobile number
+    /// portability, see [`Metadata::is_mobile_number_portable`].
+    ///
+    /// Numbers without a known region, like non-geographical ones, are never
+    /// portable.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// let number = phonenumber::parse(None, "+44 7912 345678").unwrap();
+    /// assert!(number.is_mobile_number_portable());
+    /// ```
+    pub fn is_mobile_number_portable(&self) -> bool {
+        self.is_mobile_number_portable_with(&DATABASE)
+    }
+
+    /// Check if the region of the phone number supports mobile number
+    /// portability with the given `Database`.
+    pub fn is_mobile_number_portable_with(&self, database: &Database) -> bool {
+        self.metadata(database)
+            .map_or(false, Metadata::is_mobile_number_portable)
+    }
+
+    /// Check if the phone number is valid, telling apart numbers whose region
+    /// is unknown from numbers invalid for their region, see
+    /// [`validation_detail`](crate::validation_detail).
+    pub fn validation_detail(&self) -> validator::ValidationDetail {
+        validator::validation_detail(self)
+    }
+
+    /// Check if the phone number is valid with the given `Database`, telling
+    /// apart numbers whose region is unknown from numbers invalid for their
+    /// region.
+    pub fn validation_detail_with(&self, database: &Database) -> validator::ValidationDetail {
+        validator::validation_detail_with(database, self)
+    }
+
+    /// Check if the phone number is valid and determine its [`Type`] with the
+    /// given `Database`, in a single pass.
+    ///
+    /// This is the same as calling [`is_valid_with`](Self::is_valid_with) and
+    /// [`number_type`](Self::number_type), without matching the number
+    /// against the metadata twice.
+    pub fn validate_full(&self, database: &Database) -> (bool, Type) {
+        let kind = self.number_type(database);
+        (kind != Type::Unknown, kind)
+    }
+
+    /// Determine the [`Type`] of the phone number with the default `Database`.
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use phonenumber::Type;
+    ///
+    /// let number = phonenumber::parse(None, "+44 7912 345678").unwrap();
+    /// assert_eq!(Type::Mobile, number.number_type_default());
+    /// ```
+    pub fn number_type_default(&self) -> Type {
+        self.number_type(&DATABASE)
+    }
+
+    /// Check if the phone number is assigned to a geographical area, that is
+    /// a fixed-line number, or a mobile number in a country assigning mobile
+    /// numbers geographically.
+    ///
+    /// Toll-free, premium rate and other non-geographical numbers are not.
+    pub fn is_geographical(&self) -> bool {
+        self.is_geographical_with(&DATABASE)
+    }
+
+    /// Check if the phone number is assigned to a geographical area with the
+    /// given `Database`.
+    pub fn is_geographical_with(&self, database: &Database) -> bool {
+        match self.number_type(database) {
+            Type::FixedLine | Type::FixedLineOrMobile => true,
+            Type::Mobile => consts::GEO_MOBILE_COUNTRIES.contains(&self.code.value()),
+            _ => false,
+        }
+    }
 }
 
 impl<'a> Country<'a> {
@@ -237,7 +795,12 @@ impl<'a> Country<'a> {
     }
 
     pub fn id(&self) -> Option<country::Id> {
-        self.0.metadata(&DATABASE).and_then(|m| m.id().parse().ok())
+        self.id_with(self.1)
+    }
+
+    /// Get the country ID, as found in the given `Database`.
+    pub fn id_with(&self, database: &Database) -> Option<country::Id> {
+        self.0.metadata(database).and_then(|m| m.id().parse().ok())
     }
 }
 
@@ -252,7 +815,8 @@ impl<'a> Deref for Country<'a> {
 #[cfg(test)]
 mod test {
     use crate::country::{self, Id::*};
-    use crate::metadata::DATABASE;
+    use crate::error;
+    use crate::metadata::{self, Database, DATABASE};
     use crate::Type;
     use crate::{parser, Mode, PhoneNumber};
     use anyhow::Context;
@@ -281,7 +845,7 @@ mod test {
     // Case for issues
     // https://github.com/whisperfish/rust-phonenumber/issues/46 and
     // https://github.com/whisperfish/rust-phonenumber/issues/47
-    // #[case(parsed("+1 520-878-2491"), US)]
+    #[case(parsed("+1 520-878-2491"), Some(US), Type::FixedLineOrMobile)]
     fn phone_numbers(
         #[case] number: PhoneNumber,
         #[case] country: Option<country::Id>,
@@ -301,7 +865,6 @@ mod test {
     }
 
     #[apply(phone_numbers)]
-    #[ignore]
     // Format-parse roundtrip
     fn round_trip_parsing(
         #[case] number: PhoneNumber,
@@ -315,14 +878,19 @@ mod test {
             None
         };
 
+        // Non-geographical numbers have no country to parse their national
+        // format with.
+        if mode == Mode::National && country_hint.is_none() {
+            return Ok(());
+        }
+
         let formatted = number.format().mode(mode).to_string();
         let parsed = parser::parse(country_hint, &formatted).with_context(|| {
             format!("parsing {number} after formatting in {mode:?} mode as {formatted}")
         })?;
 
-        // impl Eq for PhoneNumber does not consider differently parsed phone numbers to be equal.
-        // E.g., parsing 047409110 with BE country hint is the same phone number as +32474091150,
-        // but Eq considers them different.
+        // Numbers parsed from different formats differ in the source of their
+        // country code, which isn't compared.
         assert_eq!(number, parsed);
 
         Ok(())
@@ -335,5 +903,407 @@ mod test {
         #[case] r#type: Type,
     ) {
         assert_eq!(r#type, number.number_type(&DATABASE));
+        assert_eq!(r#type, number.number_type_default());
+        assert_eq!((true, r#type), number.validate_full(&DATABASE));
+        assert_eq!(number.is_valid(), number.validate_full(&DATABASE).0);
+    }
+
+    #[test]
+    fn has_significant_leading_zeros() {
+        assert!(parsed("+39 06 1234 5678").has_significant_leading_zeros());
+        assert!(!parsed("+39 312 345 6789").has_significant_leading_zeros());
+        assert!(!parsed("+44 20 7031 3000").has_significant_leading_zeros());
+    }
+
+    #[test]
+    fn is_geographical() {
+        assert!(parsed("+1 650 253 0000").is_geographical());
+        assert!(parsed("+44 20 7031 3000").is_geographical());
+        assert!(parsed("+55 11 96123 4567").is_geographical());
+
+        assert!(!parsed("+44 7912 345678").is_geographical());
+        assert!(!parsed("+1 800 253 0000").is_geographical());
+        assert!(!parsed("+611900123456").is_geographical());
+        assert!(!parsed("+80012340000").is_geographical());
+    }
+
+    #[test]
+    fn validate_full() {
+        let invalid = parsed("+1 2530000");
+        assert_eq!((false, Type::Unknown), invalid.validate_full(&DATABASE));
+        assert_eq!(invalid.is_valid(), invalid.validate_full(&DATABASE).0);
+    }
+
+    #[test]
+    fn type_names() {
+        for kind in [
+            Type::FixedLine,
+            Type::Mobile,
+            Type::FixedLineOrMobile,
+            Type::TollFree,
+            Type::PremiumRate,
+            Type::SharedCost,
+            Type::PersonalNumber,
+            Type::Voip,
+            Type::Pager,
+            Type::Uan,
+            Type::Emergency,
+            Type::Voicemail,
+            Type::ShortCode,
+            Type::StandardRate,
+            Type::Carrier,
+            Type::NoInternational,
+            Type::Unknown,
+        ] {
+            assert_eq!(format!("\"{kind}\""), serde_json::to_string(&kind).unwrap());
+            assert_eq!(kind, kind.to_string().parse::<Type>().unwrap());
+        }
+
+        assert_eq!("fixed_line_or_mobile", Type::FixedLineOrMobile.to_string());
+        assert_eq!(Type::Mobile, "MOBILE".parse::<Type>().unwrap());
+        assert_eq!(Type::TollFree, "Toll_Free".parse::<Type>().unwrap());
+        assert!("tollfree".parse::<Type>().is_err());
+    }
+
+    #[test]
+    fn nanpa_round_trip() {
+        for region in DATABASE.regions_for(1) {
+            let meta = DATABASE.metadata(region).unwrap();
+
+            for kind in [
+                Type::FixedLine,
+                Type::Mobile,
+                Type::TollFree,
+                Type::PremiumRate,
+                Type::PersonalNumber,
+                Type::Voip,
+                Type::Pager,
+                Type::Uan,
+                Type::Voicemail,
+            ] {
+                let example = match meta.descriptors().get(kind).and_then(|d| d.example()) {
+                    Some(example) => example,
+                    None => continue,
+                };
+                let number = parser::parse(Some(region), example).unwrap();
+
+                for mode in [
+                    Mode::E164,
+                    Mode::International,
+                    Mode::National,
+                    Mode::Rfc3966,
+                    Mode::E123,
+                    Mode::E123National,
+                ] {
+                    let formatted = number.format().mode(mode).to_string();
+                    let hint = if mode == Mode::National || mode == Mode::E123National {
+                        Some(region)
+                    } else {
+                        None
+                    };
+
+                    let parsed = parser::parse(hint, &formatted)
+                        .with_context(|| format!("parsing {formatted} of {region:?}"))
+                        .unwrap();
+                    assert_eq!(number, parsed, "{formatted} of {region:?}");
+                    assert_eq!(
+                        number.country().id(),
+                        parsed.country().id(),
+                        "{formatted} of {region:?}"
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn country_with() {
+        let database = metadata::edited(|metadata| {
+            metadata.retain(|m| m.id.as_deref() == Some("GB"));
+        });
+
+        let jersey = parser::parse_with(&database, None, "+44 1534 456789").unwrap();
+        assert_eq!(Some(JE), jersey.country().id());
+        assert_eq!(Some(GB), jersey.country_with(&database).id());
+        assert_eq!(Some(GB), jersey.country().id_with(&database));
+        assert_eq!(Some(JE), jersey.country_with(&database).id_with(&DATABASE));
+        assert_eq!(44, jersey.country_with(&database).code());
+
+        let nz = parsed("+64 3 331 6005");
+        assert_eq!(Some(NZ), nz.country().id());
+        assert_eq!(None, nz.country_with(&database).id());
+    }
+
+    #[test]
+    fn format_groups() {
+        let number = parsed("+44 20 7031 3000 ext. 123");
+        for (mode, groups) in [
+            (Mode::International, &["20", "7031", "3000"][..]),
+            (Mode::E123, &["20", "7031", "3000"]),
+            (Mode::Rfc3966, &["20", "7031", "3000"]),
+            (Mode::National, &["020", "7031", "3000"]),
+            (Mode::E123National, &["020", "7031", "3000"]),
+            (Mode::E164, &["2070313000"]),
+            (Mode::NationalRaw, &["2070313000"]),
+        ] {
+            assert_eq!(groups, &number.format_groups(mode)[..], "{mode:?}");
+        }
+
+        let number = parsed("+1 650 253 0000");
+        assert_eq!(
+            vec!["650", "253", "0000"],
+            number.format_groups(Mode::International)
+        );
+        assert_eq!(
+            vec!["650", "253", "0000"],
+            number.format_groups(Mode::National)
+        );
+
+        let number = parser::parse(Some(BR), "012 3121286979").unwrap();
+        assert_eq!(
+            vec!["0", "12", "31", "2128", "6979"],
+            number.format_groups(Mode::National)
+        );
+        assert_eq!(
+            vec!["31", "2128", "6979"],
+            number.format_groups(Mode::International)
+        );
+
+        let empty = Database::from(Vec::new()).unwrap();
+        assert!(number.format_groups_with(&empty, Mode::National).is_empty());
+    }
+
+    #[test]
+    fn canonical() {
+        let number = parser::parse_with_options(
+            &DATABASE,
+            Some(GB),
+            "020 7031 3000 ext. 12",
+            crate::ParseOptions::new().keep_groups(true),
+        )
+        .unwrap();
+        let canonical = number.canonical();
+
+        assert_eq!(number, canonical);
+        assert_eq!(country::Source::Default, number.country().source());
+        assert_eq!(country::Source::Plus, canonical.country().source());
+        assert_eq!(None, canonical.original_groups());
+        assert_eq!(number.national(), canonical.national());
+        assert_eq!(number.extension(), canonical.extension());
+        assert_eq!(parsed("+44 20 7031 3000 ext. 12"), canonical);
+        assert_eq!(canonical, canonical.canonical());
+        assert_eq!(
+            parser::parse(Some(GB), "00 44 20 7031 3000 ext. 12")
+                .unwrap()
+                .canonical(),
+            canonical
+        );
+    }
+
+    #[test]
+    fn is_mobile_number_portable() {
+        assert!(parsed("+44 7912 345678").is_mobile_number_portable());
+        assert!(parsed("+1 650 253 0000").is_mobile_number_portable());
+        assert!(!parsed("+80012340000").is_mobile_number_portable());
+
+        // Jersey shares its code with Great Britain, but has no portability.
+        let jersey = DATABASE
+            .metadata(JE)
+            .and_then(|m| m.descriptors().mobile())
+            .and_then(|d| d.example())
+            .unwrap();
+        let jersey = parser::parse(Some(JE), jersey).unwrap();
+        assert_eq!(Some(JE), jersey.country().id());
+        assert!(!jersey.is_mobile_number_portable());
+    }
+
+    #[test]
+    fn possible_lengths() {
+        let number = parsed("+1 650 253 0000");
+        assert_eq!(vec![10], number.possible_lengths());
+        assert_eq!(&[10][..], number.possible_lengths_for(Type::Mobile));
+        assert_eq!(&[10][..], number.possible_lengths_for(Type::TollFree));
+
+        let number = parsed("+49 30 1234567");
+        let any = number.possible_lengths_for(Type::FixedLine);
+        assert!(any.contains(&9));
+        assert_eq!(any, &number.possible_lengths()[..]);
+        assert!(number.possible_lengths_for(Type::Voip).is_empty());
+
+        let number = PhoneNumber::from_parts(49, 1, 0).unwrap();
+        assert_eq!(Type::Unknown, number.number_type(&DATABASE));
+        for length in [4, 5, 10, 11, 12, 13, 14, 15] {
+            assert!(number.possible_lengths().contains(&length), "{length}");
+        }
+    }
+
+    #[test]
+    fn try_from() {
+        let number = parsed("+44 20 7031 3000");
+        assert_eq!(number, PhoneNumber::try_from("+44 20 7031 3000").unwrap());
+        assert_eq!(
+            number,
+            PhoneNumber::try_from(String::from("+44 20 7031 3000")).unwrap()
+        );
+
+        assert!(matches!(
+            PhoneNumber::try_from("020 7031 3000"),
+            Err(error::Parse::InvalidCountryCode)
+        ));
+    }
+
+    #[test]
+    fn to_string_shortcuts() {
+        let number = parsed("+1 650 253 0000");
+        assert_eq!("+16502530000", number.to_e164());
+        assert_eq!("+1 650-253-0000", number.to_international());
+        assert_eq!("(650) 253-0000", number.to_national());
+        assert_eq!("+1 650 253 0000", number.to_e123());
+
+        let number = parsed("+39 06 1234 5678");
+        for (mode, formatted) in [
+            (Mode::E164, number.to_e164()),
+            (Mode::International, number.to_international()),
+            (Mode::National, number.to_national()),
+            (Mode::E123, number.to_e123()),
+        ] {
+            assert_eq!(number.format().mode(mode).to_string(), formatted);
+        }
+    }
+
+    #[test]
+    fn format_with_carrier() {
+        let number = parsed("+55 21 3121 2869");
+        assert_eq!("0 15 (21) 3121-2869", number.format_with_carrier("15"));
+        assert_eq!("0 31 (21) 3121-2869", number.format_with_carrier("31"));
+        assert_eq!(number.to_national(), number.format_with_carrier(""));
+
+        // The number's own carrier is not used.
+        let number = parser::parse(Some(BR), "012 3121286979").unwrap();
+        assert_eq!(Some("12"), number.carrier().map(|c| &**c));
+        assert_eq!("0 15 (31) 2128-6979", number.format_with_carrier("15"));
+
+        // Regions without a carrier code formatting rule ignore it.
+        let number = parsed("+44 20 7031 3000");
+        assert_eq!("020 7031 3000", number.format_with_carrier("15"));
+    }
+
+    #[test]
+    fn format_with_preferred_carrier() {
+        let number = parser::parse(Some(BR), "012 3121286979").unwrap();
+        assert_eq!(
+            "0 12 (31) 2128-6979",
+            number.format_with_preferred_carrier("15")
+        );
+        assert_eq!(
+            number.to_national(),
+            number.format_with_preferred_carrier("")
+        );
+
+        let number = parsed("+55 31 2128 6979");
+        assert_eq!(None, number.carrier());
+        assert_eq!(
+            "0 15 (31) 2128-6979",
+            number.format_with_preferred_carrier("15")
+        );
+        assert_eq!(
+            number.to_national(),
+            number.format_with_preferred_carrier("")
+        );
+    }
+
+    #[test]
+    fn from_parts() {
+        let number = PhoneNumber::from_parts(44, 2070313000, 0).unwrap();
+        assert_eq!(country::Source::Number, number.code().source());
+        assert_eq!(Some(GB), number.country().id());
+        assert_eq!(parsed("+44 20 7031 3000").national(), number.national());
+        assert!(number.is_valid());
+
+        let number = PhoneNumber::from_parts(39, 612345678, 1)
+            .unwrap()
+            .with_extension("12")
+            .with_carrier("3");
+        assert_eq!(
+            "+39 06 1234 5678 ext. 12",
+            number.format().mode(Mode::International).to_string()
+        );
+        assert_eq!(Some("12"), number.extension().map(|e| &**e));
+        assert_eq!(Some("3"), number.carrier().map(|c| &**c));
+
+        assert!(matches!(
+            PhoneNumber::from_parts(999, 1234567, 0),
+            Err(error::Parse::InvalidCountryCode)
+        ));
+        assert!(matches!(
+            PhoneNumber::from_parts(44, 1 << 56, 0),
+            Err(error::Parse::TooLong)
+        ));
+    }
+
+    #[test]
+    fn ordering() {
+        let mut numbers = [
+            parsed("+441212345678"),
+            parsed("+39 06 1234 5678"),
+            parsed("+1 520 878 2491 ext. 2"),
+            parsed("+39 6 1234 5678"),
+            parsed("+1 520 878 2491"),
+            parser::parse(Some(US), "520 878 2491").unwrap(),
+        ];
+        numbers.sort();
+
+        let formatted = numbers
+            .iter()
+            .map(|n| n.format().mode(Mode::Rfc3966).to_string())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            formatted,
+            [
+                "tel:+1-520-878-2491",
+                "tel:+1-520-878-2491",
+                "tel:+1-520-878-2491;ext=2",
+                "tel:+39-612345678",
+                "tel:+39-06-1234-5678",
+                "tel:+44-121-234-5678",
+            ]
+        );
+        // Numbers only differing in their source are equal, and keep their
+        // order in a stable sort.
+        assert_eq!(numbers[0], numbers[1]);
+        assert_eq!(numbers[0].code().source(), country::Source::Plus);
+        assert_eq!(numbers[1].code().source(), country::Source::Default);
+    }
+
+    #[test]
+    fn source_ignored() {
+        use std::collections::HashSet;
+
+        let numbers = [
+            parsed("+64 3 331 6005"),
+            parser::parse(Some(NZ), "03 331 6005").unwrap(),
+            parser::parse(Some(US), "011 64 3 331 6005").unwrap(),
+            parser::parse(Some(NZ), "64 3 331 6005").unwrap(),
+        ];
+
+        let sources = numbers
+            .iter()
+            .map(|n| n.code().source())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            vec![
+                country::Source::Plus,
+                country::Source::Default,
+                country::Source::Idd,
+                country::Source::Number,
+            ],
+            sources
+        );
+
+        assert!(numbers.iter().all(|n| n == &numbers[0]));
+        assert!(numbers
+            .iter()
+            .all(|n| n.cmp(&numbers[0]) == std::cmp::Ordering::Equal));
+        assert_eq!(1, numbers.iter().collect::<HashSet<_>>().len());
     }
 }
@@ -0,0 +1,164 @@
+// Copyright (C) 2017 1aim GmbH
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! Alternative serde representations of a `PhoneNumber`, to be used with
+//! `#[serde(with = "...")]`.
+//!
+//! The derived implementation serializes a `PhoneNumber` as a struct, these
+//! modules instead serialize it as a string and parse it back on
+//! deserialization.
+//!
+//! # Example
+//!
+//! ```
+//! use phonenumber::PhoneNumber;
+//! use serde_derive::{Deserialize, Serialize};
+//!
+//! #[derive(Serialize, Deserialize)]
+//! struct Contact {
+//!     #[serde(with = "phonenumber::serde::e164")]
+//!     phone: PhoneNumber,
+//! }
+//! ```
+
+use crate::formatter::Mode;
+use crate::parser;
+use crate::phone_number::PhoneNumber;
+use serde::de::Error;
+use serde::{Deserialize, Deserializer, Serializer};
+
+/// Serialize as an E.164 string, e.g. `"+16502530000"`.
+///
+/// The extension and carrier code are lost in the round trip, use
+/// [`rfc3966`] to keep the extension.
+pub mod e164 {
+    use crate::formatter::Mode;
+    use crate::phone_number::PhoneNumber;
+    use serde::{Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        number: &PhoneNumber,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::serialize(number, Mode::E164, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<PhoneNumber, D::Error> {
+        super::deserialize(deserializer)
+    }
+}
+
+/// Serialize as an RFC3966 URI, e.g. `"tel:+1-650-253-0000;ext=123"`.
+///
+/// The carrier code is lost in the round trip. Only RFC3966 URIs are accepted
+/// on deserialization.
+pub mod rfc3966 {
+    use crate::formatter::Mode;
+    use crate::metadata::DATABASE;
+    use crate::parser;
+    use crate::phone_number::PhoneNumber;
+    use serde::de::Error;
+    use serde::{Deserialize, Deserializer, Serializer};
+
+    pub fn serialize<S: Serializer>(
+        number: &PhoneNumber,
+        serializer: S,
+    ) -> Result<S::Ok, S::Error> {
+        super::serialize(number, Mode::Rfc3966, serializer)
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(
+        deserializer: D,
+    ) -> Result<PhoneNumber, D::Error> {
+        let string = String::deserialize(deserializer)?;
+        parser::parse_rfc3966_with(&DATABASE, string).map_err(D::Error::custom)
+    }
+}
+
+fn serialize<S: Serializer>(
+    number: &PhoneNumber,
+    mode: Mode,
+    serializer: S,
+) -> Result<S::Ok, S::Error> {
+    serializer.collect_str(&number.format().mode(mode))
+}
+
+fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<PhoneNumber, D::Error> {
+    let string = String::deserialize(deserializer)?;
+    parser::parse(None, string).map_err(D::Error::custom)
+}
+
+#[cfg(test)]
+mod test {
+    use crate::parser;
+    use crate::phone_number::PhoneNumber;
+    use serde_derive::{Deserialize, Serialize};
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct Contact {
+        #[serde(with = "crate::serde::e164")]
+        e164: PhoneNumber,
+
+        #[serde(with = "crate::serde::rfc3966")]
+        rfc3966: PhoneNumber,
+    }
+
+    #[test]
+    fn round_trip() {
+        let number = parser::parse(None, "+1 650 253 0000 ext. 123").unwrap();
+        let contact = Contact {
+            e164: number.clone(),
+            rfc3966: number.clone(),
+        };
+
+        let json = serde_json::to_string(&contact).unwrap();
+        assert_eq!(
+            r#"{"e164":"+16502530000","rfc3966":"tel:+1-650-253-0000;ext=123"}"#,
+            json
+        );
+
+        let contact: Contact = serde_json::from_str(&json).unwrap();
+        assert_eq!(number, contact.rfc3966);
+        assert_eq!(None, contact.e164.extension());
+        assert_eq!(number.national(), contact.e164.national());
+    }
+
+    #[test]
+    fn rfc3966() {
+        #[derive(Deserialize)]
+        struct Uri {
+            #[serde(with = "crate::serde::rfc3966")]
+            number: PhoneNumber,
+        }
+
+        let uri: Uri =
+            serde_json::from_str(r#"{"number":"tel:03-331-6005;ext=12;phone-context=+64"}"#)
+                .unwrap();
+        assert_eq!(64, uri.number.code().value());
+        assert_eq!(33316005, uri.number.national().value());
+        assert_eq!(Some("12"), uri.number.extension().map(|e| &**e));
+
+        assert!(serde_json::from_str::<Uri>(r#"{"number":"+1 650 253 0000 ext. 123"}"#).is_err());
+    }
+
+    #[test]
+    fn invalid() {
+        assert!(serde_json::from_str::<Contact>(
+            r#"{"e164":"not a number","rfc3966":"tel:+1-650-253-0000"}"#
+        )
+        .is_err());
+    }
+}
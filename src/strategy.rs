@@ -0,0 +1,150 @@
+// Copyright (C) 2017 1aim GmbH
+//
+// Licensed under the Apache License, Version 2.0 (the "License");
+// you may not use this file except in compliance with the License.
+// You may obtain a copy of the License at
+//
+// http://www.apache.org/licenses/LICENSE-2.0
+//
+// Unless required by applicable law or agreed to in writing, software
+// distributed under the License is distributed on an "AS IS" BASIS,
+// WITHOUT WARRANTIES OR CONDITIONS OF ANY KIND, either express or implied.
+// See the License for the specific language governing permissions and
+// limitations under the License.
+
+//! [`proptest`](https://docs.rs/proptest) strategies generating valid phone
+//! numbers, to property test code consuming a `PhoneNumber`.
+//!
+//! # Example
+//!
+//! ```
+//! use phonenumber::{country, strategy, Type};
+//! use proptest::prelude::*;
+//! use proptest::test_runner::TestRunner;
+//!
+//! let mut runner = TestRunner::default();
+//! let numbers = strategy::valid_for(Some(country::GB), Some(Type::Mobile));
+//!
+//! runner
+//!     .run(&numbers, |number| {
+//!         prop_assert!(number.to_e164().starts_with("+447"));
+//!         Ok(())
+//!     })
+//!     .unwrap();
+//! ```
+
+use crate::country;
+use crate::metadata::DATABASE;
+use crate::national_number::NationalNumber;
+use crate::parser;
+use crate::phone_number::{PhoneNumber, Type};
+use crate::validator;
+use proptest::prelude::*;
+use proptest::sample::select;
+
+/// How many trailing digits of an example number are randomized at most.
+const RANDOM_DIGITS: u32 = 4;
+
+/// Generate valid phone numbers of any region and type.
+pub fn valid() -> impl Strategy<Value = PhoneNumber> {
+    valid_for(None, None)
+}
+
+/// Generate valid phone numbers, optionally restricted to the given region
+/// and type of number.
+///
+/// The numbers are built from the example numbers in the metadata, with up to
+/// the last four digits replaced by random ones. A number that ends up invalid,
+/// or of another region or type, is replaced by the example itself.
+///
+/// # Panics
+///
+/// If the metadata has no example of a valid number for the region and type.
+pub fn valid_for(
+    region: Option<country::Id>,
+    kind: Option<Type>,
+) -> impl Strategy<Value = PhoneNumber> {
+    let examples = DATABASE
+        .all_example_numbers()
+        .filter(|&(id, of, _)| region.map_or(true, |r| r == id) && kind.map_or(true, |k| k == of))
+        .filter_map(|(id, kind, example)| {
+            parser::parse(Some(id), example)
+                .ok()
+                .filter(|number| is_valid(number, id, kind))
+                .map(|number| (id, kind, number))
+        })
+        .collect::<Vec<_>>();
+
+    assert!(
+        !examples.is_empty(),
+        "no valid example numbers for {region:?} and {kind:?}"
+    );
+
+    (select(examples), 0..10u64.pow(RANDOM_DIGITS)).prop_map(|((id, kind, example), random)| {
+        let value = example.national.value();
+        let digits = value.to_string().len() as u32;
+        let modulus = 10u64.pow(RANDOM_DIGITS.min(digits / 2));
+
+        let mut number = example.clone();
+        number.national = NationalNumber::new(
+            value - value % modulus + random % modulus,
+            example.national.zeros(),
+        )
+        .unwrap();
+
+        if is_valid(&number, id, kind) {
+            number
+        } else {
+            example
+        }
+    })
+}
+
+/// Check the number is valid, and of the given region and type.
+fn is_valid(number: &PhoneNumber, region: country::Id, kind: Type) -> bool {
+    validator::is_valid_for_region(number, region) && number.number_type(&DATABASE) == kind
+}
+
+/// Valid phone numbers, optionally restricted to a region and type of number,
+/// see [`valid_for`].
+impl Arbitrary for PhoneNumber {
+    type Parameters = (Option<country::Id>, Option<Type>);
+    type Strategy = BoxedStrategy<Self>;
+
+    fn arbitrary_with((region, kind): Self::Parameters) -> Self::Strategy {
+        valid_for(region, kind).boxed()
+    }
+}
+
+#[cfg(test)]
+mod test {
+    use crate::country;
+    use crate::metadata::DATABASE;
+    use crate::phone_number::{PhoneNumber, Type};
+    use crate::strategy;
+    use proptest::prelude::*;
+
+    proptest! {
+        #[test]
+        fn valid(number in strategy::valid()) {
+            prop_assert!(crate::is_valid(&number));
+        }
+
+        #[test]
+        fn valid_for(number in strategy::valid_for(Some(country::JP), Some(Type::Mobile))) {
+            prop_assert_eq!(Some(country::JP), number.country().id());
+            prop_assert_eq!(Type::Mobile, number.number_type(&DATABASE));
+        }
+
+        #[test]
+        fn arbitrary(number in any_with::<PhoneNumber>((Some(country::DE), None))) {
+            prop_assert!(crate::is_valid_for_region(&number, country::DE));
+        }
+    }
+
+    #[test]
+    #[should_panic]
+    fn no_examples() {
+        let _ = strategy::valid_for(Some(country::US), Some(Type::Emergency));
+    }
+}
@@ -20,6 +20,21 @@ use crate::parser::helper::Number as ParseNumber;
 use crate::phone_number::{PhoneNumber, Type};
 use either::*;
 
+/// The types of numbers with their own national number descriptor, making up
+/// the valid numbers and the possible lengths of a region.
+pub(crate) const DESCRIPTOR_TYPES: [Type; 10] = [
+    Type::FixedLine,
+    Type::Mobile,
+    Type::TollFree,
+    Type::PremiumRate,
+    Type::SharedCost,
+    Type::PersonalNumber,
+    Type::Voip,
+    Type::Pager,
+    Type::Uan,
+    Type::Voicemail,
+];
+
 /// Possible outcomes when testing if a `PhoneNumber` is possible.
 #[derive(Copy, Clone, Eq, PartialEq, Debug)]
 pub enum Validation {
@@ -76,6 +91,41 @@ impl Validation {
     }
 }
 
+/// Reasons for a `PhoneNumber` not being valid, see [`is_valid_reason`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ValidationIssue {
+    /// There is no metadata for the country code of the number.
+    UnknownCountryCode,
+
+    /// The number does not match the general pattern of numbers for its
+    /// country.
+    NoMatch,
+
+    /// The number matches the general pattern of numbers for its country, but
+    /// no descriptor of a specific type, and the descriptors for the given
+    /// types are not loaded (e.g. in a trimmed database), so the number may
+    /// be one of them.
+    DescriptorMissing(Vec<Type>),
+
+    /// The number matches the general pattern of numbers for its country, but
+    /// none of its descriptors.
+    NoMatchingType,
+}
+
+/// Detailed outcome of validating a `PhoneNumber`, see [`validation_detail`].
+#[derive(Clone, Eq, PartialEq, Debug)]
+pub enum ValidationDetail {
+    /// The number is valid, and is of the given type.
+    Valid(Type),
+
+    /// The country code of the number is shared by several regions, but the
+    /// number does not belong to any of them.
+    UnknownRegion,
+
+    /// The number is not valid for its region, for the given reason.
+    Invalid(ValidationIssue),
+}
+
 /// Check if the provided string is a viable phone number.
 pub fn is_viable<S: AsRef<str>>(string: S) -> bool {
     let string = string.as_ref();
@@ -94,17 +144,321 @@ pub fn is_valid(number: &PhoneNumber) -> bool {
 
 /// Check if the phone number is valid with the given `Database`.
 pub fn is_valid_with(database: &Database, number: &PhoneNumber) -> bool {
+    is_valid_detailed_with(database, number).0
+}
+
+/// Check if the phone number is valid, returning the region whose metadata it
+/// was validated against.
+pub fn is_valid_detailed(number: &PhoneNumber) -> (bool, Option<country::Id>) {
+    is_valid_detailed_with(&DATABASE, number)
+}
+
+/// Check if the phone number is valid with the given `Database`, returning the
+/// region whose metadata it was validated against.
+///
+/// For country codes shared by several regions (e.g. `+1`) the region is
+/// picked by its leading digits or by the number matching one of its types.
+/// The region is `None` for non-geographical entities, or when no region could
+/// be picked at all, and is returned even if the number turns out not to be
+/// valid for it.
+pub fn is_valid_detailed_with(
+    database: &Database,
+    number: &PhoneNumber,
+) -> (bool, Option<country::Id>) {
     let code = number.country().code();
-    let national = number.national.to_string();
-    source_for(database, code, &national)
+    let national = number.national.digits();
+
+    let source = match source_for(database, code, &national) {
+        Some(source) => source,
+        None => return (false, None),
+    };
+
+    let meta = match source {
+        Left(region) => database.by_id(region.as_ref()),
+        Right(code) => database.main_metadata(code),
+    };
+    let valid = meta
+        .map(|meta| number_type(meta, &national) != Type::Unknown)
+        .unwrap_or(false);
+
+    (valid, source.left())
+}
+
+/// Check if the phone number is valid, returning its type, or the reason it is
+/// not valid.
+pub fn is_valid_reason(number: &PhoneNumber) -> Result<Type, ValidationIssue> {
+    is_valid_reason_with(&DATABASE, number)
+}
+
+/// Check if the phone number is valid with the given `Database`, returning its
+/// type, or the reason it is not valid.
+///
+/// Unlike [`is_valid_with`] this tells apart numbers that may be valid, but
+/// whose type of number is missing from the database.
+pub fn is_valid_reason_with(
+    database: &Database,
+    number: &PhoneNumber,
+) -> Result<Type, ValidationIssue> {
+    let code = number.country().code();
+    let national = number.national.digits();
+    let main = || database.main_metadata(code);
+
+    // If no region of a shared country code matched, use the main one.
+    let meta = source_for(database, code, &national)
         .and_then(|meta| match meta {
             Left(region) => database.by_id(region.as_ref()),
-            Right(code) => database.by_code(&code).and_then(|m| m.into_iter().next()),
+            Right(_) => main(),
         })
-        .map(|meta| number_type(meta, &national) != Type::Unknown)
+        .or_else(main)
+        .ok_or(ValidationIssue::UnknownCountryCode)?;
+
+    reason(meta, &national)
+}
+
+/// Check if the phone number is valid, telling apart numbers not belonging to
+/// any region of a shared country code from numbers invalid for their region.
+///
+/// # Example
+///
+/// ```
+/// use phonenumber::{Type, ValidationDetail};
+///
+/// let number = phonenumber::parse(None, "+1 650 253 0000").unwrap();
+/// assert_eq!(
+///     ValidationDetail::Valid(Type::FixedLineOrMobile),
+///     phonenumber::validation_detail(&number)
+/// );
+///
+/// let number = phonenumber::parse(None, "+1 200 253 0000").unwrap();
+/// assert_eq!(
+///     ValidationDetail::UnknownRegion,
+///     phonenumber::validation_detail(&number)
+/// );
+/// ```
+pub fn validation_detail(number: &PhoneNumber) -> ValidationDetail {
+    validation_detail_with(&DATABASE, number)
+}
+
+/// Check if the phone number is valid with the given `Database`, telling apart
+/// numbers not belonging to any region of a shared country code from numbers
+/// invalid for their region.
+pub fn validation_detail_with(database: &Database, number: &PhoneNumber) -> ValidationDetail {
+    let code = number.country().code();
+    let national = number.national.digits();
+
+    let meta = match source_for(database, code, &national) {
+        Some(Left(region)) => database.by_id(region.as_ref()),
+        Some(Right(_)) => database.main_metadata(code),
+        None if database.by_code(&code).is_some() => return ValidationDetail::UnknownRegion,
+        None => None,
+    };
+
+    match meta.map(|meta| reason(meta, &national)) {
+        Some(Ok(kind)) => ValidationDetail::Valid(kind),
+        Some(Err(issue)) => ValidationDetail::Invalid(issue),
+        None => ValidationDetail::Invalid(ValidationIssue::UnknownCountryCode),
+    }
+}
+
+/// The type of the national number in the region of the metadata, or the
+/// reason it is not valid there.
+fn reason(meta: &Metadata, national: &str) -> Result<Type, ValidationIssue> {
+    match number_type(meta, national) {
+        Type::Unknown if !meta.descriptors.general.is_match(national) => {
+            Err(ValidationIssue::NoMatch)
+        }
+
+        Type::Unknown => {
+            let missing = DESCRIPTOR_TYPES
+                .iter()
+                .copied()
+                .filter(|&kind| meta.descriptors.get(kind).is_none())
+                .collect::<Vec<_>>();
+
+            if missing.is_empty() {
+                Err(ValidationIssue::NoMatchingType)
+            } else {
+                Err(ValidationIssue::DescriptorMissing(missing))
+            }
+        }
+
+        kind => Ok(kind),
+    }
+}
+
+/// Check if the phone number is valid and belongs to the given region.
+pub fn is_valid_for_region(number: &PhoneNumber, region: country::Id) -> bool {
+    is_valid_for_region_with(&DATABASE, number, region)
+}
+
+/// Check if the phone number is valid and belongs to the given region with
+/// the given `Database`.
+///
+/// For country codes shared by multiple regions this is false when the number
+/// belongs to another region, even if it is otherwise valid.
+pub fn is_valid_for_region_with(
+    database: &Database,
+    number: &PhoneNumber,
+    region: country::Id,
+) -> bool {
+    let national = number.national.digits();
+
+    match source_for(database, number.code.value(), &national) {
+        Some(Left(id)) if id == region => database
+            .by_id(region.as_ref())
+            .map(|meta| number_type(meta, &national) != Type::Unknown)
+            .unwrap_or(false),
+
+        _ => false,
+    }
+}
+
+/// Check if the phone number can be dialled from outside its country.
+pub fn can_be_internationally_dialled(number: &PhoneNumber) -> bool {
+    can_be_internationally_dialled_with(&DATABASE, number)
+}
+
+/// Check if the phone number can be dialled from outside its country with the
+/// given `Database`.
+///
+/// Numbers whose country is unknown are assumed to be diallable.
+pub fn can_be_internationally_dialled_with(database: &Database, number: &PhoneNumber) -> bool {
+    let meta = if let Some(meta) = number.metadata(database) {
+        meta
+    } else {
+        return true;
+    };
+
+    !meta
+        .descriptors
+        .no_international
+        .as_ref()
+        .map(|d| d.is_match(&number.national.digits()))
         .unwrap_or(false)
 }
 
+/// Check if the phone number can be dialled as-is from the given country.
+pub fn can_dial_from(number: &PhoneNumber, from: country::Id) -> bool {
+    can_dial_from_with(&DATABASE, number, from)
+}
+
+/// Check if the phone number can be dialled as-is from the given country with
+/// the given `Database`.
+///
+/// The following heuristics are used:
+///
+/// - the number has to be valid,
+/// - valid numbers can always be dialled from a country sharing their country
+///   code, and from any other country unless they are restricted to domestic
+///   dialling.
+pub fn can_dial_from_with(database: &Database, number: &PhoneNumber, from: country::Id) -> bool {
+    let meta = if let Some(meta) = number.metadata(database) {
+        meta
+    } else {
+        return false;
+    };
+
+    if number_type(meta, &number.national.digits()) == Type::Unknown {
+        return false;
+    }
+
+    database.country_code_for(from) == Some(number.code.value())
+        || can_be_internationally_dialled_with(database, number)
+}
+
+/// Check if the length of the phone number is possible for its type.
+pub fn validate(number: &PhoneNumber) -> Validation {
+    validate_with(&DATABASE, number)
+}
+
+/// Check if the length of the phone number is possible for its type with the
+/// given `Database`.
+///
+/// Numbers not matching any type are checked against the lengths of all the
+/// types of their country.
+pub fn validate_with(database: &Database, number: &PhoneNumber) -> Validation {
+    let code = number.country().code();
+    let national = number.national.digits();
+    let main = || database.main_metadata(code);
+
+    // If no region of a shared country code matched, use the main one.
+    let meta = match number.metadata(database).or_else(main) {
+        Some(meta) => meta,
+        None => return Validation::InvalidCountryCode,
+    };
+
+    match number_type(meta, &national) {
+        Type::Unknown => any_length(meta, national.len() as u16),
+
+        kind => length(
+            meta,
+            &ParseNumber {
+                national: (&*national).into(),
+                ..Default::default()
+            },
+            kind,
+        ),
+    }
+}
+
+/// Check if the phone number has a possible length for its country, without
+/// matching it against the patterns of valid numbers.
+///
+/// This is a cheap check to filter out numbers before [`is_valid`].
+pub fn is_possible(number: &PhoneNumber) -> bool {
+    is_possible_with(&DATABASE, number)
+}
+
+/// Check if the phone number has a possible length for its country with the
+/// given `Database`.
+pub fn is_possible_with(database: &Database, number: &PhoneNumber) -> bool {
+    is_possible_reason_with(database, number).is_possible()
+}
+
+/// Check if the phone number has a possible length for its country, returning
+/// how the length compares to the possible ones.
+pub fn is_possible_reason(number: &PhoneNumber) -> Validation {
+    is_possible_reason_with(&DATABASE, number)
+}
+
+/// Check if the phone number has a possible length for its country with the
+/// given `Database`, returning how the length compares to the possible ones.
+///
+/// Numbers only long enough to be dialled within their area (e.g. 7 digit US
+/// numbers) are [`Validation::IsPossibleLocalOnly`].
+pub fn is_possible_reason_with(database: &Database, number: &PhoneNumber) -> Validation {
+    database
+        .main_metadata(number.country().code())
+        .map(|meta| any_length(meta, number.national.digits().len() as u16))
+        .unwrap_or(Validation::InvalidCountryCode)
+}
+
+/// Check a length against the lengths of all the types of numbers.
+pub(crate) fn any_length(meta: &Metadata, length: u16) -> Validation {
+    let (possible, local) = all_lengths(meta);
+    classify(length, &possible, &local)
+}
+
+/// The possible lengths, sorted, and the possible local only lengths of all the
+/// types of numbers.
+pub(crate) fn all_lengths(meta: &Metadata) -> (Vec<u16>, Vec<u16>) {
+    let mut possible = meta.descriptors.general.possible_length.clone();
+    let mut local = meta.descriptors.general.possible_local_length.clone();
+
+    for desc in DESCRIPTOR_TYPES
+        .iter()
+        .filter_map(|&kind| meta.descriptors.get(kind))
+    {
+        possible.extend(&desc.possible_length);
+        local.extend(&desc.possible_local_length);
+    }
+
+    possible.sort_unstable();
+    possible.dedup();
+
+    (possible, local)
+}
+
 pub fn length(meta: &Metadata, number: &ParseNumber<'_>, kind: Type) -> Validation {
     let desc = if let Some(desc) = meta.descriptors().get(kind) {
         desc
@@ -112,14 +466,23 @@ pub fn length(meta: &Metadata, number: &ParseNumber<'_>, kind: Type) -> Validati
         return Validation::InvalidLength;
     };
 
-    let length = number.national.len() as u16;
-    let local = &desc.possible_local_length[..];
+    // Use the lengths of the specific type, falling back to the general ones.
     let possible = if desc.possible_length.is_empty() {
-        &desc.possible_length[..]
-    } else {
         &meta.descriptors.general.possible_length[..]
+    } else {
+        &desc.possible_length[..]
     };
 
+    classify(
+        number.national.len() as u16,
+        possible,
+        &desc.possible_local_length,
+    )
+}
+
+/// Check a length against the sorted possible lengths and the local only
+/// lengths.
+fn classify(length: u16, possible: &[u16], local: &[u16]) -> Validation {
     if possible.is_empty() {
         return Validation::InvalidLength;
     }
@@ -147,6 +510,16 @@ pub fn source_for(
     code: u16,
     national: &str,
 ) -> Option<Either<country::Id, u16>> {
+    // Only the regions that may have numbers starting with the same three
+    // digits are checked, instead of the leading digits of every region.
+    if let Some(candidates) = database.candidates(code, national) {
+        return candidates
+            .iter()
+            .find(|(meta, certain)| *certain || is_source(meta, national))
+            .and_then(|(meta, _)| meta.id.parse().ok())
+            .map(Left);
+    }
+
     let regions = database.region(&code)?;
     if regions.len() == 1 {
         return if regions[0] == "001" {
@@ -160,15 +533,7 @@ pub fn source_for(
     }
 
     for region in regions {
-        let meta = database.by_id(region).unwrap();
-
-        if let Some(pattern) = meta.leading_digits.as_ref() {
-            if let Some(index) = pattern.find(national) {
-                if index.start() == 0 {
-                    return Some(Left(region.parse().unwrap()));
-                }
-            }
-        } else if number_type(meta, national) != Type::Unknown {
+        if is_source(database.by_id(region).unwrap(), national) {
             return Some(Left(region.parse().unwrap()));
         }
     }
@@ -176,6 +541,16 @@ pub fn source_for(
     None
 }
 
+/// Check if the national number belongs to the region of the metadata, out of
+/// the regions sharing its country code.
+fn is_source(meta: &Metadata, national: &str) -> bool {
+    if let Some(pattern) = meta.leading_digits.as_ref() {
+        pattern.find(national).map_or(false, |m| m.start() == 0)
+    } else {
+        number_type(meta, national) != Type::Unknown
+    }
+}
+
 pub fn number_type(meta: &Metadata, value: &str) -> Type {
     if !meta.descriptors.general.is_match(value) {
         return Type::Unknown;
@@ -305,14 +680,75 @@ pub fn number_type(meta: &Metadata, value: &str) -> Type {
         return Type::Mobile;
     }
 
+    // These are only defined in databases built from short number metadata.
+    if meta
+        .descriptors
+        .standard_rate
+        .as_ref()
+        .map(|d| d.is_match(value))
+        .unwrap_or(false)
+    {
+        return Type::StandardRate;
+    }
+
+    if meta
+        .descriptors
+        .carrier
+        .as_ref()
+        .map(|d| d.is_match(value))
+        .unwrap_or(false)
+    {
+        return Type::Carrier;
+    }
+
     Type::Unknown
 }
 
+/// Get every type of number whose descriptor matches the national number, in
+/// the order they are checked by [`number_type`].
+///
+/// Unlike [`number_type`] fixed-line and mobile numbers are reported as
+/// separate [`Type::FixedLine`] and [`Type::Mobile`] matches.
+pub fn matching_types(meta: &Metadata, value: &str) -> Vec<Type> {
+    const TYPES: [Type; 12] = [
+        Type::PremiumRate,
+        Type::TollFree,
+        Type::SharedCost,
+        Type::Voip,
+        Type::PersonalNumber,
+        Type::Pager,
+        Type::Uan,
+        Type::Voicemail,
+        Type::FixedLine,
+        Type::Mobile,
+        Type::StandardRate,
+        Type::Carrier,
+    ];
+
+    if !meta.descriptors.general.is_match(value) {
+        return Vec::new();
+    }
+
+    TYPES
+        .iter()
+        .copied()
+        .filter(|&kind| {
+            meta.descriptors
+                .get(kind)
+                .map(|d| d.is_match(value))
+                .unwrap_or(false)
+        })
+        .collect()
+}
+
 #[cfg(test)]
 mod test {
     use crate::country;
+    use crate::metadata::{self, Database, DATABASE};
     use crate::parser;
-    use crate::validator;
+    use crate::parser::helper::Number as ParseNumber;
+    use crate::phone_number::{PhoneNumber, Type};
+    use crate::validator::{self, Validation, ValidationIssue};
 
     #[test]
     fn validate() {
@@ -368,4 +804,320 @@ mod test {
             &parser::parse(None, "+800 123456789").unwrap()
         ));
     }
+
+    #[test]
+    fn is_valid_for_region() {
+        let us = parser::parse(None, "+1 650 253 0000").unwrap();
+        assert!(validator::is_valid_for_region(&us, country::US));
+        assert!(!validator::is_valid_for_region(&us, country::CA));
+
+        let ca = parser::parse(None, "+1 613 782 7274").unwrap();
+        assert!(validator::is_valid_for_region(&ca, country::CA));
+        assert!(!validator::is_valid_for_region(&ca, country::US));
+
+        let gb = parser::parse(None, "+44 7912 345678").unwrap();
+        assert!(validator::is_valid_for_region(&gb, country::GB));
+        assert!(!validator::is_valid_for_region(&gb, country::JE));
+        assert!(!validator::is_valid_for_region(&gb, country::DE));
+
+        let invalid = parser::parse(None, "+1 2530000").unwrap();
+        assert!(!validator::is_valid_for_region(&invalid, country::US));
+    }
+
+    #[test]
+    fn is_valid_detailed() {
+        let us = parser::parse(None, "+1 650 253 0000").unwrap();
+        assert_eq!((true, Some(country::US)), validator::is_valid_detailed(&us));
+
+        let ca = parser::parse(None, "+1 613 782 7274").unwrap();
+        assert_eq!((true, Some(country::CA)), validator::is_valid_detailed(&ca));
+
+        let it = parser::parse(None, "+39 06 1234 5678").unwrap();
+        assert_eq!((true, Some(country::IT)), validator::is_valid_detailed(&it));
+
+        let toll_free = parser::parse(None, "+800 1234 5678").unwrap();
+        assert_eq!((true, None), validator::is_valid_detailed(&toll_free));
+
+        let invalid = parser::parse(None, "+1 2530000").unwrap();
+        assert_eq!((false, None), validator::is_valid_detailed(&invalid));
+
+        for number in [us, ca, it, toll_free, invalid] {
+            assert_eq!(
+                validator::is_valid(&number),
+                validator::is_valid_detailed(&number).0
+            );
+        }
+    }
+
+    #[test]
+    fn can_dial_from() {
+        let mobile = parser::parse(None, "+44 7912 345678").unwrap();
+        assert!(validator::can_be_internationally_dialled(&mobile));
+        assert!(validator::can_dial_from(&mobile, country::GB));
+        assert!(validator::can_dial_from(&mobile, country::US));
+
+        let domestic = parser::parse(None, "+61 1800 123 456").unwrap();
+        assert!(!validator::can_be_internationally_dialled(&domestic));
+        assert!(validator::can_dial_from(&domestic, country::AU));
+        assert!(validator::can_dial_from(&domestic, country::CX));
+        assert!(!validator::can_dial_from(&domestic, country::NZ));
+
+        let invalid = parser::parse(None, "+1 2530000").unwrap();
+        assert!(!validator::can_dial_from(&invalid, country::US));
+    }
+
+    #[test]
+    fn length() {
+        let gb = DATABASE.by_id(country::GB.as_ref()).unwrap();
+        let number = |national: &'static str| ParseNumber {
+            national: national.into(),
+            ..Default::default()
+        };
+
+        // Mobile numbers are only possible with 10 digits, while fixed-line
+        // numbers can also have 9 digits.
+        assert_eq!(
+            Validation::IsPossible,
+            validator::length(gb, &number("121234567"), Type::FixedLine)
+        );
+        assert_eq!(
+            Validation::TooShort,
+            validator::length(gb, &number("791234567"), Type::Mobile)
+        );
+        assert_eq!(
+            Validation::IsPossible,
+            validator::length(gb, &number("7912345678"), Type::Mobile)
+        );
+        assert_eq!(
+            Validation::TooLong,
+            validator::length(gb, &number("79123456789"), Type::Mobile)
+        );
+    }
+
+    #[test]
+    fn validate_length() {
+        let validate = |number| validator::validate(&parser::parse(None, number).unwrap());
+
+        assert_eq!(Validation::IsPossible, validate("+44 7912 345678"));
+        assert_eq!(Validation::IsPossible, validate("+44 121 234 5678"));
+        assert_eq!(Validation::IsPossible, validate("+1 650 253 0000"));
+        assert_eq!(Validation::IsPossible, validate("+39 06 1234 5678"));
+
+        assert_eq!(Validation::TooShort, validate("+44 12"));
+        assert_eq!(Validation::TooLong, validate("+44 7912 345678 901"));
+        assert_eq!(Validation::TooLong, validate("+1 650 253 00000"));
+        assert_eq!(Validation::TooShort, validate("+1 650 253"));
+
+        let unknown = PhoneNumber::from_parts(44, 2070313000, 0).map(|mut number| {
+            number.code.value = 999;
+            number
+        });
+        assert_eq!(
+            Validation::InvalidCountryCode,
+            validator::validate(&unknown.unwrap())
+        );
+    }
+
+    #[test]
+    fn is_possible() {
+        let possible = |number| validator::is_possible(&parser::parse(None, number).unwrap());
+
+        assert!(possible("+44 7912 345678"));
+        assert!(possible("+1 650 253 0000"));
+        assert!(possible("+39 06 1234 5678"));
+
+        // Possible, but not valid.
+        assert!(possible("+1 253 000 0000"));
+        assert!(!validator::is_valid(
+            &parser::parse(None, "+1 253 000 0000").unwrap()
+        ));
+
+        assert!(!possible("+44 12"));
+        assert!(!possible("+1 650 253 00000"));
+    }
+
+    #[test]
+    fn is_possible_reason() {
+        let reason = |country, number| {
+            validator::is_possible_reason(&parser::parse(country, number).unwrap())
+        };
+
+        assert_eq!(Validation::IsPossible, reason(None, "+1 650 253 0000"));
+        assert_eq!(
+            Validation::IsPossibleLocalOnly,
+            reason(Some(country::US), "253 0000")
+        );
+        assert_eq!(Validation::TooShort, reason(None, "+44 12"));
+        assert_eq!(Validation::TooLong, reason(None, "+1 650 253 00000"));
+
+        let mut unknown = parser::parse(None, "+44 20 7031 3000").unwrap();
+        unknown.code.value = 999;
+        assert_eq!(
+            Validation::InvalidCountryCode,
+            validator::is_possible_reason(&unknown)
+        );
+        assert!(!validator::is_possible(&unknown));
+    }
+
+    #[test]
+    fn matching_types() {
+        let types = |region: &str, number: &str| {
+            validator::matching_types(
+                DATABASE.by_id(region).unwrap(),
+                &parser::parse(None, number).unwrap().national().to_string(),
+            )
+        };
+
+        assert_eq!(
+            vec![Type::FixedLine, Type::Mobile],
+            types("US", "+1 650 253 0000")
+        );
+        assert_eq!(vec![Type::Mobile], types("GB", "+44 7912 345678"));
+        assert_eq!(vec![Type::TollFree], types("GB", "+44 800 123 4567"));
+        assert_eq!(Vec::<Type>::new(), types("GB", "+44 791234567"));
+
+        // The single type is always the first match.
+        for (region, number) in [
+            ("US", "+1 650 253 0000"),
+            ("GB", "+44 7912 345678"),
+            ("IT", "+39 06 1234 5678"),
+        ] {
+            let kind = match parser::parse(None, number).unwrap().number_type(&DATABASE) {
+                Type::FixedLineOrMobile => Type::FixedLine,
+                kind => kind,
+            };
+
+            assert_eq!(Some(&kind), types(region, number).first(), "{number}");
+        }
+    }
+
+    #[test]
+    fn standard_rate_and_carrier() {
+        // Define the fixed-line numbers of the United Kingdom as standard rate
+        // and the mobile ones as carrier specific.
+        let database = metadata::edited(|metadata| {
+            for meta in metadata {
+                if meta.id.as_deref() == Some("GB") {
+                    meta.standard_rate = meta.fixed_line.take();
+                    meta.carrier = meta.mobile.take();
+                }
+            }
+        });
+        let kind = |number| {
+            parser::parse_with(&database, None, number)
+                .unwrap()
+                .number_type(&database)
+        };
+
+        assert_eq!(Type::StandardRate, kind("+44 20 7031 3000"));
+        assert_eq!(Type::Carrier, kind("+44 7912 345678"));
+        assert_eq!(Type::TollFree, kind("+44 800 123 4567"));
+    }
+
+    #[test]
+    fn is_valid_reason() {
+        let mobile = parser::parse(None, "+44 7912 345678").unwrap();
+
+        assert_eq!(Ok(Type::Mobile), validator::is_valid_reason(&mobile));
+        assert_eq!(
+            Err(ValidationIssue::NoMatch),
+            validator::is_valid_reason(&parser::parse(None, "+1 2530000").unwrap())
+        );
+
+        // Drop the mobile descriptor of the United Kingdom.
+        let database = metadata::edited(|metadata| {
+            for meta in metadata {
+                if meta.id.as_deref() == Some("GB") {
+                    meta.mobile = None;
+                }
+            }
+        });
+
+        assert!(!validator::is_valid_with(&database, &mobile));
+        assert!(matches!(
+            validator::is_valid_reason_with(&database, &mobile),
+            Err(ValidationIssue::DescriptorMissing(missing)) if missing.contains(&Type::Mobile)
+        ));
+
+        let fixed = parser::parse(None, "+44 20 7031 3000").unwrap();
+        assert_eq!(
+            Ok(Type::FixedLine),
+            validator::is_valid_reason_with(&database, &fixed)
+        );
+    }
+
+    #[test]
+    fn source_for() {
+        // The regions sharing a code are found the same as by checking every
+        // one of them in order.
+        for meta in DATABASE.iter() {
+            let regions = DATABASE.by_code(&meta.country_code()).unwrap();
+            if regions.len() == 1 {
+                continue;
+            }
+
+            for kind in [Type::FixedLine, Type::Mobile, Type::TollFree] {
+                let example = meta.descriptors().get(kind).and_then(|d| d.example());
+
+                if let Some(example) = example {
+                    let expected = regions
+                        .iter()
+                        .find(|m| validator::is_source(m, example))
+                        .map(|m| either::Left(m.id().parse().unwrap()));
+
+                    assert_eq!(
+                        expected,
+                        validator::source_for(&DATABASE, meta.country_code(), example),
+                        "{} {}",
+                        meta.id(),
+                        example
+                    );
+                }
+            }
+        }
+    }
+
+    #[test]
+    fn validation_detail() {
+        use crate::validator::ValidationDetail;
+
+        let number = parser::parse(None, "+44 20 7031 3000").unwrap();
+        assert_eq!(
+            ValidationDetail::Valid(Type::FixedLine),
+            validator::validation_detail(&number)
+        );
+        assert_eq!(
+            ValidationDetail::Valid(Type::TollFree),
+            validator::validation_detail(&parser::parse(None, "+800 1234 5678").unwrap())
+        );
+
+        // No region of the shared code matches, the main region is only
+        // used for the reason it's not valid.
+        let number = parser::parse(None, "+1 200 253 0000").unwrap();
+        assert_eq!(None, number.country().id());
+        assert_eq!(
+            ValidationDetail::UnknownRegion,
+            validator::validation_detail(&number)
+        );
+        assert!(validator::is_valid_reason(&number).is_err());
+
+        let number = parser::parse(None, "+44 7912 34567").unwrap();
+        assert_eq!(
+            ValidationDetail::UnknownRegion,
+            validator::validation_detail(&number)
+        );
+
+        // Numbers of regions not sharing their code always have a region.
+        let number = parser::parse(None, "+64 1 331 6005").unwrap();
+        assert_eq!(
+            ValidationDetail::Invalid(ValidationIssue::NoMatch),
+            number.validation_detail()
+        );
+
+        let empty = Database::from(Vec::new()).unwrap();
+        assert_eq!(
+            ValidationDetail::Invalid(ValidationIssue::UnknownCountryCode),
+            number.validation_detail_with(&empty)
+        );
+    }
 }
@@ -1,4 +1,4 @@
-use phonenumber::parse;
+use phonenumber::{parse, Mode};
 use proptest::prelude::*;
 
 proptest! {
@@ -31,4 +31,22 @@ proptest! {
         let parsed = parse(None, &s).expect("valid Belgian number");
         prop_assert_eq!(parsed.country().id(), phonenumber::country::BE.into());
     }
+
+    #[test]
+    fn nanpa_round_trip(
+        s in "\\+1[2-9][0-9]{2}[2-9][0-9]{6}",
+        mode in prop_oneof![
+            Just(Mode::E164),
+            Just(Mode::International),
+            Just(Mode::Rfc3966),
+            Just(Mode::E123),
+        ],
+    ) {
+        let number = parse(None, &s).expect("possible NANPA number");
+        let formatted = number.format().mode(mode).to_string();
+        let parsed = parse(None, &formatted).expect("formatted NANPA number");
+
+        prop_assert_eq!(&number, &parsed);
+        prop_assert_eq!(number.country().id(), parsed.country().id());
+    }
 }